@@ -0,0 +1,13 @@
+use super::*;
+
+#[error_code]
+pub enum ComplianceHookError {
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("The sovereign coin has no mint yet")]
+    MintNotSet,
+    #[msg("Transfer parties must both be on the allowlist")]
+    NotAllowlisted,
+    #[msg("A transfer party is on the denylist")]
+    Denylisted,
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+#[event]
+pub struct AccessListInitializedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub mint: Pubkey,
+    pub mode: AccessListMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessListModeSetEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub mode: AccessListMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessEntryAddedEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub wallet: Pubkey,
+    pub mode: AccessListMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessEntryRemovedEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub wallet: Pubkey,
+    pub mode: AccessListMode,
+    pub timestamp: i64,
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddAccessEntry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(address = access_list.sovereign_coin)]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+        seeds::program = stablecoin_factory::ID,
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &authority.key()) @ ComplianceHookError::Unauthorized,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"access_list", access_list.mint.as_ref()],
+        bump = access_list.bump,
+    )]
+    pub access_list: Box<Account<'info, AccessList>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AccessEntry::INIT_SPACE,
+        seeds = [b"access_entry", access_list.mint.as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub access_entry: Box<Account<'info, AccessEntry>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl AddAccessEntry<'_> {
+    pub fn handler(ctx: Context<Self>, wallet: Pubkey) -> Result<()> {
+        let clock = Clock::get()?;
+        
+        let access_entry = &mut ctx.accounts.access_entry;
+        access_entry.bump = ctx.bumps.access_entry;
+        access_entry.mint = ctx.accounts.access_list.mint;
+        access_entry.wallet = wallet;
+        access_entry.added_at = clock.unix_timestamp;
+        
+        let access_list = &mut ctx.accounts.access_list;
+        access_list.entries_count = access_list.entries_count.saturating_add(1);
+        
+        // Emit event
+        emit_cpi!(AccessEntryAddedEvent {
+            authority: ctx.accounts.authority.key(),
+            mint: access_list.mint,
+            wallet,
+            mode: access_list.mode,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
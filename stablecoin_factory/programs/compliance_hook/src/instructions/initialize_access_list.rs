@@ -0,0 +1,112 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAccessList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(constraint = sovereign_coin.mint == mint.key() @ ComplianceHookError::MintNotSet)]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+        seeds::program = stablecoin_factory::ID,
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &authority.key()) @ ComplianceHookError::Unauthorized,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AccessList::INIT_SPACE,
+        seeds = [b"access_list", mint.key().as_ref()],
+        bump
+    )]
+    pub access_list: Box<Account<'info, AccessList>>,
+    
+    /// CHECK: ExtraAccountMetaList TLV account, written in the handler
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(InitializeAccessList::extra_account_metas(&sovereign_coin)?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeAccessList<'_> {
+    /// Accounts appended to every transfer, after the four standard ones
+    /// (source, mint, destination, owner) and the meta list itself.
+    pub fn extra_account_metas(sovereign_coin: &Account<SovereignCoin>) -> Result<Vec<ExtraAccountMeta>> {
+        Ok(vec![
+            // 5: access_list
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"access_list".to_vec() },
+                    Seed::AccountKey { index: 1 },
+                ],
+                false,
+                false,
+            )?,
+            // 6: entry for the owner of the source token account
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"access_entry".to_vec() },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+                ],
+                false,
+                false,
+            )?,
+            // 7: entry for the owner of the destination token account
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"access_entry".to_vec() },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
+                ],
+                false,
+                false,
+            )?,
+            // 8, 9: coin and factory, for the transfer pause flags
+            ExtraAccountMeta::new_with_pubkey(&sovereign_coin.key(), false, false)?,
+            ExtraAccountMeta::new_with_pubkey(&sovereign_coin.factory, false, false)?,
+        ])
+    }
+
+    pub fn handler(ctx: Context<Self>, mode: AccessListMode) -> Result<()> {
+        let access_list = &mut ctx.accounts.access_list;
+        access_list.bump = ctx.bumps.access_list;
+        access_list.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        access_list.mint = ctx.accounts.mint.key();
+        access_list.mode = mode;
+        access_list.entries_count = 0;
+        
+        let extra_account_metas = Self::extra_account_metas(&ctx.accounts.sovereign_coin)?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AccessListInitializedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: access_list.sovereign_coin,
+            mint: access_list.mint,
+            mode,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+pub mod initialize_access_list;
+pub mod set_access_list_mode;
+pub mod add_access_entry;
+pub mod remove_access_entry;
+pub mod transfer_hook;
+
+pub use initialize_access_list::*;
+pub use set_access_list_mode::*;
+pub use add_access_entry::*;
+pub use remove_access_entry::*;
+pub use transfer_hook::*;
@@ -0,0 +1,57 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveAccessEntry<'info> {
+    /// CHECK: Receives the entry's rent
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(address = access_list.sovereign_coin)]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+        seeds::program = stablecoin_factory::ID,
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &authority.key()) @ ComplianceHookError::Unauthorized,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"access_list", access_list.mint.as_ref()],
+        bump = access_list.bump,
+    )]
+    pub access_list: Box<Account<'info, AccessList>>,
+    
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"access_entry", access_list.mint.as_ref(), wallet.as_ref()],
+        bump = access_entry.bump,
+    )]
+    pub access_entry: Box<Account<'info, AccessEntry>>,
+}
+
+impl RemoveAccessEntry<'_> {
+    pub fn handler(ctx: Context<Self>, wallet: Pubkey) -> Result<()> {
+        let access_list = &mut ctx.accounts.access_list;
+        access_list.entries_count = access_list.entries_count.saturating_sub(1);
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AccessEntryRemovedEvent {
+            authority: ctx.accounts.authority.key(),
+            mint: access_list.mint,
+            wallet,
+            mode: access_list.mode,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAccessListMode<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(address = access_list.sovereign_coin)]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+        seeds::program = stablecoin_factory::ID,
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &authority.key()) @ ComplianceHookError::Unauthorized,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"access_list", access_list.mint.as_ref()],
+        bump = access_list.bump,
+    )]
+    pub access_list: Box<Account<'info, AccessList>>,
+}
+
+impl SetAccessListMode<'_> {
+    pub fn handler(ctx: Context<Self>, mode: AccessListMode) -> Result<()> {
+        let access_list = &mut ctx.accounts.access_list;
+        access_list.mode = mode;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AccessListModeSetEvent {
+            authority: ctx.accounts.authority.key(),
+            mint: access_list.mint,
+            mode,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
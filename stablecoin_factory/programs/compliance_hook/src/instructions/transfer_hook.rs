@@ -0,0 +1,80 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(token::mint = mint)]
+    pub destination_token: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: Source owner or delegate, already verified by the token program
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: ExtraAccountMetaList, only used by the token program to resolve accounts
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"access_list", mint.key().as_ref()],
+        bump = access_list.bump,
+    )]
+    pub access_list: Box<Account<'info, AccessList>>,
+    
+    /// CHECK: AccessEntry PDA of the source owner, empty when not listed
+    #[account(
+        seeds = [b"access_entry", mint.key().as_ref(), source_token.owner.as_ref()],
+        bump
+    )]
+    pub source_entry: UncheckedAccount<'info>,
+    
+    /// CHECK: AccessEntry PDA of the destination owner, empty when not listed
+    #[account(
+        seeds = [b"access_entry", mint.key().as_ref(), destination_token.owner.as_ref()],
+        bump
+    )]
+    pub destination_entry: UncheckedAccount<'info>,
+    
+    #[account(address = access_list.sovereign_coin)]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(address = sovereign_coin.factory)]
+    pub factory: AccountLoader<'info, Factory>,
+}
+
+impl TransferHook<'_> {
+    pub fn handler(ctx: Context<Self>, _amount: u64) -> Result<()> {
+        // Seizures move coins as the coin PDA, the mint's permanent delegate,
+        // and must reach listed and unlisted holders alike
+        let sovereign_coin_key = ctx.accounts.sovereign_coin.key();
+        if ctx.accounts.owner.key() == sovereign_coin_key
+            && ctx.accounts.source_token.owner != sovereign_coin_key
+        {
+            return Ok(());
+        }
+        
+        // Guardian pause of transfers, factory-wide or for this coin
+        ctx.accounts.sovereign_coin.require_not_paused(&*ctx.accounts.factory.load()?, PAUSE_TRANSFER)?;
+        
+        let source_listed = AccessEntry::is_listed(&ctx.accounts.source_entry);
+        let destination_listed = AccessEntry::is_listed(&ctx.accounts.destination_entry);
+        
+        match ctx.accounts.access_list.mode {
+            AccessListMode::Allowlist => require!(
+                source_listed && destination_listed,
+                ComplianceHookError::NotAllowlisted
+            ),
+            AccessListMode::Denylist => require!(
+                !source_listed && !destination_listed,
+                ComplianceHookError::Denylisted
+            ),
+        }
+        
+        Ok(())
+    }
+}
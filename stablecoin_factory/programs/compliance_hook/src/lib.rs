@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use stablecoin_factory::{Factory, Role, Roles, SovereignCoin, PAUSE_TRANSFER};
+
+pub mod error;
+pub mod instructions;
+pub mod state;
+pub mod events;
+
+pub use error::ComplianceHookError;
+pub use instructions::*;
+pub use state::*;
+pub use events::*;
+
+declare_id!("99KzLLdjb9ij9QtAEymPUGeAdjk97wQFWJwc6ZiZQCNa");
+
+#[program]
+pub mod compliance_hook {
+    use super::*;
+
+    /// Create the coin's access list and register the extra accounts the
+    /// token program must pass to every transfer
+    pub fn initialize_access_list(ctx: Context<InitializeAccessList>, mode: AccessListMode) -> Result<()> {
+        InitializeAccessList::handler(ctx, mode)
+    }
+
+    pub fn set_access_list_mode(ctx: Context<SetAccessListMode>, mode: AccessListMode) -> Result<()> {
+        SetAccessListMode::handler(ctx, mode)
+    }
+
+    pub fn add_access_entry(ctx: Context<AddAccessEntry>, wallet: Pubkey) -> Result<()> {
+        AddAccessEntry::handler(ctx, wallet)
+    }
+
+    pub fn remove_access_entry(ctx: Context<RemoveAccessEntry>, wallet: Pubkey) -> Result<()> {
+        RemoveAccessEntry::handler(ctx, wallet)
+    }
+
+    /// Invoked by Token-2022 on every transfer of a hooked coin
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        TransferHook::handler(ctx, amount)
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AccessListMode {
+    Allowlist,                 // Only listed wallets may send or receive
+    Denylist,                  // Listed wallets may neither send nor receive
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AccessList {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,  // Coin whose authority manages this list
+    pub mint: Pubkey,            // The hooked Token-2022 mint
+    pub mode: AccessListMode,    // How entries are interpreted
+    pub entries_count: u32,      // Number of AccessEntry PDAs currently open
+}
+
+/// One listed wallet. Existence of the PDA is the listing; the transfer hook
+/// resolves it from the token account owners, so unlisted wallets simply
+/// have no account at the address.
+#[account]
+#[derive(InitSpace)]
+pub struct AccessEntry {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub wallet: Pubkey,
+    pub added_at: i64,
+}
+
+impl AccessEntry {
+    pub fn is_listed(info: &AccountInfo) -> bool {
+        info.owner == &crate::ID && !info.data_is_empty()
+    }
+}
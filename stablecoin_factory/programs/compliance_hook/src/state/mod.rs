@@ -0,0 +1,5 @@
+use super::*;
+
+pub mod access_list;
+
+pub use access_list::*;
@@ -1 +1,52 @@
-pub const MAX_BOND_MAPPINGS: usize = 6;
\ No newline at end of file
+use anchor_lang::prelude::*;
+
+pub const MAX_BOND_MAPPINGS: usize = 6;
+
+// Account layout versions, raised by every change migrate_* has to transform
+pub const FACTORY_VERSION: u8 = 3;
+pub const SOVEREIGN_COIN_VERSION: u8 = 2;
+
+// Zeroed bytes at the end of Factory and SovereignCoin, taken by future fields
+pub const FACTORY_RESERVED_BYTES: usize = 128;
+pub const SOVEREIGN_COIN_RESERVED_BYTES: usize = 128;
+
+// Transfer hook program installed on Token-2022 coins that opt into compliance checks
+pub const COMPLIANCE_HOOK_PROGRAM_ID: Pubkey = pubkey!("99KzLLdjb9ij9QtAEymPUGeAdjk97wQFWJwc6ZiZQCNa");
+
+// Pause flags, used by both Factory.paused and SovereignCoin.paused
+pub const PAUSE_MINT: u8 = 1 << 0;        // Issuance, including creating new coins
+pub const PAUSE_REDEEM: u8 = 1 << 1;      // Burning coins for reserves
+pub const PAUSE_YIELD: u8 = 1 << 2;       // Yield accrual and reserve reconciliation
+pub const PAUSE_TRANSFER: u8 = 1 << 3;    // Holder-to-holder transfers, enforced by the compliance hook only
+pub const PAUSE_ALL: u8 = PAUSE_MINT | PAUSE_REDEEM | PAUSE_YIELD | PAUSE_TRANSFER;
+
+// Lower bound on Factory.timelock_delay, so every change can be seen coming.
+// Test builds (`anchor test -- --features localnet`) shorten it to seconds
+#[cfg(not(feature = "localnet"))]
+pub const MIN_TIMELOCK_DELAY: i64 = 24 * 60 * 60;
+#[cfg(feature = "localnet")]
+pub const MIN_TIMELOCK_DELAY: i64 = 2;
+
+// Upper bound on Factory.timelock_delay, so governance cannot lock itself out
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+
+// Length of the per-coin mint and redeem rate limit windows
+pub const RATE_LIMIT_WINDOW: i64 = 24 * 60 * 60;
+
+// Highest Factory.keeper_reward_bps governance may set
+pub const MAX_KEEPER_REWARD_BPS: u16 = 100;
+
+// Issuer fee tiers per coin
+pub const MAX_FEE_TIERS: usize = 4;
+
+// Longest reason accepted by freeze, thaw and seize instructions
+pub const MAX_REASON_LEN: usize = 64;
+
+// Seconds per year as used by the Token-2022 interest-bearing extension
+pub const SECONDS_PER_YEAR: i64 = 31_556_736;
+
+// Reserve status flags returned by get_reserve_status
+pub const RESERVE_STATUS_FULLY_BACKED: u8 = 1 << 0;      // Reserve NAV covers the total supply
+pub const RESERVE_STATUS_FIAT_BUFFER_MET: u8 = 1 << 1;   // Fiat reserve meets the required ratio
+pub const RESERVE_STATUS_PRICE_VALID: u8 = 1 << 2;       // Bond NAV priced from a valid oracle
+pub const RESERVE_STATUS_CACHE_IN_SYNC: u8 = 1 << 3;     // Cached amounts match token balances
@@ -30,4 +30,154 @@ pub enum StablecoinError {
     InvalidFiatCurrency,
     #[msg("The provided bond mint does not match the expected one for this currency")]
     InvalidBondMint,
-}
\ No newline at end of file
+    #[msg("The oracle account does not match the one configured for this bond")]
+    InvalidOracleAccount,
+    #[msg("No price oracle is configured for this bond")]
+    OracleNotConfigured,
+    #[msg("The oracle price is invalid or not currently trading")]
+    InvalidOraclePrice,
+    #[msg("The oracle price is stale")]
+    StaleOraclePrice,
+    #[msg("The oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+    #[msg("Basis points value must not exceed 10000")]
+    InvalidBasisPoints,
+    #[msg("This operation is paused for the whole factory")]
+    FactoryPaused,
+    #[msg("This operation is paused for this sovereign coin")]
+    SovereignCoinPaused,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("The guardian must be a different key from the issuer admin")]
+    GuardianIsAuthority,
+    #[msg("The sovereign coin is winding down")]
+    SovereignCoinWindingDown,
+    #[msg("The sovereign coin is not winding down")]
+    SovereignCoinNotWindingDown,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("The sovereign coin still has circulating supply")]
+    SupplyNotZero,
+    #[msg("An account required by the coin's metadata standard is missing")]
+    MissingMetadataAccount,
+    #[msg("Sovereign coin does not have an interest-bearing mint")]
+    NotInterestBearing,
+    #[msg("Yield was already harvested at this timestamp")]
+    HarvestTooEarly,
+    #[msg("Reason is too long")]
+    ReasonTooLong,
+    #[msg("Sovereign coin mint has no permanent delegate")]
+    PermanentDelegateNotEnabled,
+    #[msg("The issuer admin role can be transferred but not revoked")]
+    CannotRevokeIssuerAdmin,
+    #[msg("Amount exceeds the accrued yield")]
+    InsufficientAccruedYield,
+    #[msg("Invalid reserve parameters")]
+    InvalidReserveParameters,
+    #[msg("Treasury cannot be the default pubkey")]
+    InvalidTreasury,
+    #[msg("Timelock delay is out of range")]
+    InvalidTimelockDelay,
+    #[msg("Timelock delay has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("A bond mapping already exists for this currency; update it through the timelock")]
+    BondMappingExists,
+    #[msg("Mint would exceed the coin's max supply")]
+    MaxSupplyExceeded,
+    #[msg("Mint would exceed the coin's limit for this window")]
+    MintRateLimitExceeded,
+    #[msg("Redemption would exceed the coin's limit for this window")]
+    RedeemRateLimitExceeded,
+    #[msg("Limit is above the bound set by the factory")]
+    LimitAboveFactoryBound,
+    #[msg("The circuit breaker has not tripped")]
+    BreakerNotTripped,
+    #[msg("Fee tiers must start at zero, ascend by amount and fit the tier limit")]
+    InvalidFeeTiers,
+    #[msg("Fee is above the bound set by the factory")]
+    FeeAboveFactoryBound,
+    #[msg("The coin PDA does not hold the mint authority")]
+    MintAuthorityNotDelegated,
+    #[msg("Flash mints are disabled for this coin")]
+    FlashMintDisabled,
+    #[msg("Flash mint exceeds the coin's cap")]
+    FlashMintCapExceeded,
+    #[msg("A flash mint is already outstanding")]
+    FlashMintOutstanding,
+    #[msg("Flash mint must be a top-level instruction of this program")]
+    FlashMintNotTopLevel,
+    #[msg("No flash_repay for this coin follows the flash mint")]
+    FlashRepayMissing,
+    #[msg("No flash mint is outstanding")]
+    NoFlashMintOutstanding,
+    #[msg("Cannot swap a sovereign coin for itself")]
+    SameSovereignCoin,
+    #[msg("Account does not belong to the sovereign coin")]
+    InvalidSovereignCoinAccount,
+    #[msg("The FX rate is not for this currency pair")]
+    FxRateMismatch,
+    #[msg("Amount out is below the minimum")]
+    SlippageExceeded,
+    #[msg("Fiat reserve would fall below the coin's requirement")]
+    FiatReserveBelowRequirement,
+    #[msg("The redemption ticket is not queued")]
+    TicketNotQueued,
+    #[msg("Only the ticket at the front of the queue can be settled")]
+    TicketNotAtFront,
+    #[msg("Not enough fiat in the reserve to settle the ticket")]
+    InsufficientFiatReserve,
+    #[msg("Account is not a valid Stablebond account")]
+    InvalidStablebondAccount,
+    #[msg("The bond issue has not matured yet")]
+    BondNotMatured,
+    #[msg("The new bond must pay out in the same currency as the matured one")]
+    BondCurrencyMismatch,
+    #[msg("The new bond issue is not open for purchase")]
+    BondNotPurchasable,
+    #[msg("No bonds to roll over")]
+    NothingToRollOver,
+    #[msg("Bond redemption paid less than par")]
+    BondRedemptionBelowPar,
+    #[msg("Keeper reward exceeds the allowed maximum")]
+    KeeperRewardTooHigh,
+    #[msg("Reserve rebalancing is disabled")]
+    RebalancingDisabled,
+    #[msg("Fiat reserve is within the rebalance band")]
+    ReservesWithinBand,
+    #[msg("No backing bonds to sell")]
+    NoBondsToSell,
+    #[msg("The insurance vault must be passed while an insurance fee share is set")]
+    InsuranceVaultRequired,
+    #[msg("Insurance vault holds a different fiat token than the coin's reserve")]
+    InsuranceVaultMintMismatch,
+    #[msg("Not enough funds in the insurance vault")]
+    InsufficientInsuranceFund,
+    #[msg("A wallet cannot refer its own mints")]
+    SelfReferral,
+    #[msg("The referral escrow must be passed with a referrer")]
+    ReferralEscrowRequired,
+    #[msg("No referral fees to claim")]
+    NothingToClaim,
+    #[msg("Jurisdiction code must be 1 to 8 bytes")]
+    InvalidJurisdiction,
+    #[msg("Issuer has not been approved by the factory")]
+    IssuerNotApproved,
+    #[msg("Issuer approval has been revoked")]
+    IssuerRevoked,
+    #[msg("Issuer has reached its coin quota")]
+    IssuerQuotaExceeded,
+    #[msg("Wallet is not on the coin's primary market allowlist")]
+    NotAllowlisted,
+    #[msg("Account is already in the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Account layout is not one this program can migrate")]
+    UnknownAccountLayout,
+    #[msg("Transfers can only be paused on coins with the compliance hook")]
+    TransferPauseUnsupported,
+    #[msg("Coins have yet to roll over off the mapping's previous bond mint")]
+    BondMintInUse,
+    #[msg("The fiat reserve cannot pay the redemption now, so it needs a ticket and escrow to queue")]
+    RedemptionTicketRequired,
+    #[msg("The coin's fiat reserve meets its requirement, so there is no shortfall to cover")]
+    NoShortfall,
+}
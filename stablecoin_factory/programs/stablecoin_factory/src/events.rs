@@ -27,12 +27,17 @@ pub struct SovereignCoinInitializedEvent {
     pub required_reserve_percentage: u8, 
     pub fiat_amount: u64,
     pub bond_amount: u64,
+    pub creation_fee: u64,  // Lamports paid to the treasury
     pub timestamp: i64,
 }
 
 #[event]
 pub struct SovereignCoinSetupMintEvent {
     pub mint: Pubkey,
+    pub metadata_standard: MetadataStandard,
+    pub transfer_hook_enabled: bool,
+    pub interest_bearing: bool,
+    pub permanent_delegate_enabled: bool,
     pub timestamp: i64,
 }
 
@@ -54,6 +59,7 @@ pub struct SovereignCoinCreatedEvent {
     pub bond_mint: Pubkey,
     pub bond_account: Pubkey,
     pub bond_rating: u8,
+    pub metadata_standard: MetadataStandard,
     pub timestamp: i64,
 }
 
@@ -67,3 +73,507 @@ pub struct BondMappingRegisteredEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ManualPriceUpdatedEvent {
+    pub authority: Pubkey,
+    pub manual_price: Pubkey,
+    pub bond_mint: Pubkey,
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveNavUpdatedEvent {
+    pub sovereign_coin: Pubkey,
+    pub bond_price: i64,
+    pub bond_price_expo: i32,
+    pub fiat_amount: u64,
+    pub bond_amount: u64,
+    pub bond_nav: u64,
+    pub reserve_nav: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveDiscrepancyEvent {
+    pub sovereign_coin: Pubkey,
+    pub surplus_policy: SurplusPolicy,
+    pub fiat_cached: u64,
+    pub fiat_actual: u64,
+    pub fiat_surplus: u64,
+    pub fiat_shortfall: u64,
+    pub bond_cached: u64,
+    pub bond_actual: u64,
+    pub bond_surplus: u64,
+    pub bond_shortfall: u64,
+    pub fiat_amount: u64,
+    pub bond_amount: u64,
+    pub accrued_yield_fiat: u64,
+    pub accrued_yield_bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGrantedEvent {
+    pub admin: Pubkey,
+    pub scope: Pubkey,
+    pub role: Role,
+    pub old_holder: Pubkey,
+    pub new_holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevokedEvent {
+    pub admin: Pubkey,
+    pub scope: Pubkey,
+    pub role: Role,
+    pub old_holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FactoryPauseUpdatedEvent {
+    pub signer: Pubkey,
+    pub factory: Pubkey,
+    pub flags: u8,
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinPauseUpdatedEvent {
+    pub signer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub flags: u8,
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WindDownStartedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub supply: u64,
+    pub fiat_reserve_balance: u64,
+    pub bond_holding_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettleRedeemEvent {
+    pub holder: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub amount_burned: u64,
+    pub fiat_paid: u64,
+    pub bond_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinClosedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub fiat_swept: u64,
+    pub bond_swept: u64,
+    pub total_sovereign_coins: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct YieldHarvestedEvent {
+    pub sovereign_coin: Pubkey,
+    pub realized_yield: u64,     // Accrued yield valued in fiat token base units
+    pub holder_yield: u64,       // Holder share of realized_yield
+    pub holder_fiat: u64,        // Accrued fiat released into backing
+    pub holder_bond: u64,        // Accrued bonds released into backing
+    pub scaled_supply: u64,      // Supply including interest, before the new rate
+    pub elapsed: i64,            // Seconds since the previous harvest
+    pub rate_bps: i16,           // New interest rate on the mint
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HolderFreezeUpdatedEvent {
+    pub compliance_officer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub frozen: bool,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HolderSeizedEvent {
+    pub compliance_officer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub source: Pubkey,
+    pub source_owner: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccruedYieldWithdrawnEvent {
+    pub treasurer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub fiat_amount: u64,
+    pub bond_amount: u64,
+    pub accrued_yield_fiat: u64,    // Remaining after the withdrawal
+    pub accrued_yield_bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FactoryChangeQueuedEvent {
+    pub authority: Pubkey,
+    pub factory: Pubkey,
+    pub pending_change: Pubkey,
+    pub id: u64,
+    pub change: FactoryChange,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FactoryChangeExecutedEvent {
+    pub authority: Pubkey,
+    pub factory: Pubkey,
+    pub pending_change: Pubkey,
+    pub id: u64,
+    pub change: FactoryChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FactoryChangeCancelledEvent {
+    pub signer: Pubkey,
+    pub factory: Pubkey,
+    pub pending_change: Pubkey,
+    pub id: u64,
+    pub change: FactoryChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoinLimitsSetEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub max_supply: u64,
+    pub mint_limit: u64,
+    pub redeem_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTrippedEvent {
+    pub factory: Pubkey,
+    pub trip: BreakerTrip,
+    pub flags: u8,                  // Flags the breaker applies
+    pub paused: u8,                 // Factory flags after the trip
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerResetEvent {
+    pub guardian: Pubkey,
+    pub factory: Pubkey,
+    pub trip: BreakerTrip,          // Reason the breaker had tripped
+    pub paused: u8,                 // Factory flags after the reset
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoinFeesSetEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub tiers: Vec<FeeTier>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketMakerUpdatedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub wallet: Pubkey,
+    pub added: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesChargedEvent {
+    pub payer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub operation: FeeOperation,
+    pub fees: FeeBreakdown,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintAuthorityDelegatedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FlashMintConfigSetEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub cap: u64,
+    pub fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FlashMintEvent {
+    pub borrower: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FlashRepayEvent {
+    pub payer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxRateInitializedEvent {
+    pub authority: Pubkey,
+    pub fx_rate: Pubkey,
+    pub base_currency: [u8; 8],
+    pub quote_currency: [u8; 8],
+    pub expo: i32,
+    pub max_price_age: u32,
+    pub max_confidence_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxRateUpdatedEvent {
+    pub authority: Pubkey,
+    pub fx_rate: Pubkey,
+    pub base_currency: [u8; 8],
+    pub quote_currency: [u8; 8],
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxPoolWithdrawnEvent {
+    pub treasurer: Pubkey,
+    pub pool_account: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinsSwappedEvent {
+    pub user: Pubkey,
+    pub sovereign_coin_in: Pubkey,
+    pub sovereign_coin_out: Pubkey,
+    pub amount_in: u64,             // Coins burned
+    pub amount_out: u64,            // Coins minted, after the fee
+    pub fee: u64,                   // In units of the minted coin
+    pub fx_price: i64,
+    pub fx_expo: i32,
+    pub fiat_released: u64,         // Moved from the burned coin's reserve to the pool
+    pub fiat_required: u64,         // Moved from the pool to the minted coin's reserve
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionQueuedEvent {
+    pub holder: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub ticket: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub fees: FeeBreakdown,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionCancelledEvent {
+    pub owner: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub ticket: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionSettledEvent {
+    pub owner: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub ticket: Pubkey,
+    pub id: u64,
+    pub amount: u64,                // Coins burned
+    pub fiat_paid: u64,             // To the holder, after fees
+    pub insurance_fee: u64,         // Part of the protocol fee sent to the insurance vault
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinRedeemedEvent {
+    pub holder: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub amount: u64,                // Coins burned
+    pub fiat_paid: u64,             // To the holder, after fees
+    pub insurance_fee: u64,         // Part of the protocol fee sent to the insurance vault
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BondsRolledOverEvent {
+    pub cranker: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub matured_bond_mint: Pubkey,
+    pub new_bond_mint: Pubkey,
+    pub new_bond_holding: Pubkey,
+    pub bond_redeemed: u64,
+    pub fiat_proceeds: u64,
+    pub bond_purchased: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservesRebalancedEvent {
+    pub keeper: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub direction: RebalanceDirection,
+    pub target_fiat: u64,           // Fiat the reserve requirement calls for
+    pub fiat_before: u64,           // Backing fiat before the trade
+    pub fiat_moved: u64,            // Spent on or received for bonds
+    pub bond_moved: u64,            // Bought or sold
+    pub keeper_reward: u64,         // Paid in fiat from accrued yield
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceVaultInitializedEvent {
+    pub authority: Pubkey,
+    pub fiat_currency: String,
+    pub insurance_vault: Pubkey,
+    pub fiat_token_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShortfallCoveredEvent {
+    pub treasurer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub insurance_vault: Pubkey,
+    pub amount: u64,
+    pub fund_balance: u64,          // Left in the vault
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferrerRegisteredEvent {
+    pub wallet: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub referrer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinMintedEvent {
+    pub user: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub amount: u64,                // Coins paid for, fees included
+    pub fiat_deposited: u64,
+    pub insurance_fee: u64,         // Part of fiat_deposited sent to the insurance vault
+    pub net_amount: u64,            // Coins received by the user
+    pub referrer: Option<Pubkey>,   // Referrer wallet, if any
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralAccruedEvent {
+    pub wallet: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub referral_fee: u64,
+    pub referral_count: u64,        // Running totals for the referrer
+    pub total_volume: u64,
+    pub accrued: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeesClaimedEvent {
+    pub wallet: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerRegisteredEvent {
+    pub issuer: Pubkey,
+    pub issuer_profile: Pubkey,
+    pub jurisdiction: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerApprovedEvent {
+    pub authority: Pubkey,
+    pub issuer: Pubkey,
+    pub coin_quota: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerRevokedEvent {
+    pub authority: Pubkey,
+    pub issuer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowlistUpdatedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub wallet: Pubkey,
+    pub added: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PermissionlessUpdatedEvent {
+    pub authority: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub permissionless: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub authority: Pubkey,
+    pub account: Pubkey,            // Factory or SovereignCoin
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{Factory, FeeOperation, SovereignCoin, StablecoinError};
+
+/// Fees on a mint or redeem of `amount` coin base units, carried by fee events.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct FeeBreakdown {
+    pub amount: u64,
+    pub protocol_fee_bps: u16,
+    pub issuer_fee_bps: u16,
+    pub protocol_fee: u64,           // To the factory treasury
+    pub issuer_fee: u64,             // To the issuer
+    pub net_amount: u64,             // Amount left after both fees
+}
+
+/// Splits `amount` into the factory's protocol fee, the coin's issuer fee and
+/// the remainder. Market makers the issuer allowlisted pay neither fee. The
+/// issuer fee is clamped to the factory's current bound in case it was lowered
+/// after the schedule was set. Fees round down.
+pub fn calculate_fees(
+    factory: &Factory,
+    sovereign_coin: &SovereignCoin,
+    operation: FeeOperation,
+    amount: u64,
+    market_maker: bool,
+) -> Result<FeeBreakdown> {
+    let (protocol_fee_bps, issuer_fee_bps) = if market_maker {
+        (0, 0)
+    } else {
+        let protocol_fee_bps = match operation {
+            FeeOperation::Mint => factory.mint_fee_bps,
+            FeeOperation::Redeem => factory.burn_fee_bps,
+        };
+        let issuer_fee_bps = sovereign_coin
+            .fee_schedule
+            .fee_bps(operation, amount)
+            .min(factory.max_issuer_fee_bps);
+        (protocol_fee_bps, issuer_fee_bps)
+    };
+
+    let protocol_fee = bps_of(amount, protocol_fee_bps);
+    let issuer_fee = bps_of(amount, issuer_fee_bps);
+    let net_amount = amount
+        .checked_sub(protocol_fee)
+        .and_then(|net| net.checked_sub(issuer_fee))
+        .ok_or(StablecoinError::ArithmeticOverflow)?;
+
+    Ok(FeeBreakdown {
+        amount,
+        protocol_fee_bps,
+        issuer_fee_bps,
+        protocol_fee,
+        issuer_fee,
+        net_amount,
+    })
+}
+
+fn bps_of(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / 10_000) as u64
+}
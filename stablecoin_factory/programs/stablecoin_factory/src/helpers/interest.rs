@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{interest_bearing_mint::InterestBearingConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as MintState,
+};
+
+use crate::SECONDS_PER_YEAR;
+
+/// Interest-bearing config of a Token-2022 mint, if it has one.
+pub fn load_interest_config(mint_info: &AccountInfo) -> Result<Option<InterestBearingConfig>> {
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(mint.get_extension::<InterestBearingConfig>().ok().copied())
+}
+
+/// `amount` raw base units as they are displayed at `now`, with the interest
+/// accrued since the mint was initialized. Mirrors the scaling Token-2022
+/// applies in `amount_to_ui_amount`, rounded down.
+pub fn interest_scaled_amount(amount: u64, config: &InterestBearingConfig, now: i64) -> Option<u64> {
+    to_u64(amount as f64 * interest_exponent(config, now)?.exp())
+}
+
+/// Raw base units that display as `amount` at `now`; the inverse of
+/// interest_scaled_amount, rounded down.
+pub fn interest_unscaled_amount(amount: u64, config: &InterestBearingConfig, now: i64) -> Option<u64> {
+    to_u64(amount as f64 / interest_exponent(config, now)?.exp())
+}
+
+fn interest_exponent(config: &InterestBearingConfig, now: i64) -> Option<f64> {
+    let initialized_at = i64::from(config.initialization_timestamp);
+    let last_update = i64::from(config.last_update_timestamp);
+    let pre_update = (i16::from(config.pre_update_average_rate) as i128)
+        .checked_mul(last_update.checked_sub(initialized_at)? as i128)?;
+    let post_update = (i16::from(config.current_rate) as i128)
+        .checked_mul(now.checked_sub(last_update)? as i128)?;
+    Some(pre_update.checked_add(post_update)? as f64 / SECONDS_PER_YEAR as f64 / 10_000.0)
+}
+
+fn to_u64(value: f64) -> Option<u64> {
+    let value = value.floor();
+    if value.is_finite() && value >= 0.0 && value <= u64::MAX as f64 {
+        Some(value as u64)
+    } else {
+        None
+    }
+}
+
+/// Coin amount as holders see it, which is what the reserves have to cover.
+/// Equal to `amount` for mints without the interest-bearing extension.
+pub fn scaled_amount(mint_info: &AccountInfo, amount: u64, now: i64) -> Result<u64> {
+    match load_interest_config(mint_info)? {
+        Some(config) => interest_scaled_amount(amount, &config, now)
+            .ok_or_else(|| error!(crate::StablecoinError::ArithmeticOverflow)),
+        None => Ok(amount),
+    }
+}
+
+/// Raw coin amount that holders see as `amount`, the inverse of scaled_amount.
+pub fn unscaled_amount(mint_info: &AccountInfo, amount: u64, now: i64) -> Result<u64> {
+    match load_interest_config(mint_info)? {
+        Some(config) => interest_unscaled_amount(amount, &config, now)
+            .ok_or_else(|| error!(crate::StablecoinError::ArithmeticOverflow)),
+        None => Ok(amount),
+    }
+}
+
+/// Annual rate in basis points that pays `holder_yield` on `supply_value` over
+/// `elapsed` seconds, capped at what the extension can store.
+pub fn annualized_rate_bps(holder_yield: u64, supply_value: u64, elapsed: i64) -> Option<i16> {
+    if supply_value == 0 || elapsed <= 0 {
+        return Some(0);
+    }
+    let rate = (holder_yield as u128)
+        .checked_mul(10_000)?
+        .checked_mul(SECONDS_PER_YEAR as u128)?
+        / (supply_value as u128).checked_mul(elapsed as u128)?;
+    Some(rate.min(i16::MAX as u128) as i16)
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::StablecoinError;
+
+/// Upgrades an account still in its v1 layout in place. Fields are only ever
+/// appended, so the v1 bytes stay where they are: the account grows to
+/// `new_len`, `version` is written right after the v1 fields and the reserved
+/// bytes behind it start zeroed. `payer` funds the extra rent.
+pub fn migrate_v1_layout<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    discriminator: &[u8],
+    v1_len: usize,
+    new_len: usize,
+    version: u8,
+) -> Result<()> {
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *discriminator,
+            StablecoinError::UnknownAccountLayout
+        );
+        require!(data.len() != new_len, StablecoinError::AccountAlreadyMigrated);
+        require!(data.len() == v1_len, StablecoinError::UnknownAccountLayout);
+    }
+    
+    resize_account(account, payer, system_program, new_len)?;
+    account.try_borrow_mut_data()?[v1_len] = version;
+    Ok(())
+}
+
+/// Reallocates `account` to `new_len`, with `payer` topping up the rent.
+/// Bytes past the old length start zeroed.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if rent > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+    
+    account.realloc(new_len, true)?;
+    Ok(())
+}
@@ -1,3 +1,15 @@
+pub mod fees;
+pub mod interest;
+pub mod migration;
+pub mod oracle;
+pub mod redemption;
 pub mod reserve;
+pub mod stablebond;
 
+pub use fees::*;
+pub use interest::*;
+pub use migration::*;
+pub use oracle::*;
+pub use redemption::*;
 pub use reserve::*;
+pub use stablebond::*;
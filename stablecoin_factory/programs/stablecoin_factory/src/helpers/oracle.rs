@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::{BondCurrencyMapping, Factory, FxRate, ManualPrice, OracleSource, SovereignCoin, StablecoinError};
+
+// Pyth v2 price account layout (see pyth-client `PriceAccount`)
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_VERSION_2: u32 = 2;
+const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+const PYTH_EXPO_OFFSET: usize = 20;
+const PYTH_TIMESTAMP_OFFSET: usize = 96;
+const PYTH_AGG_PRICE_OFFSET: usize = 208;
+const PYTH_AGG_CONF_OFFSET: usize = 216;
+const PYTH_AGG_STATUS_OFFSET: usize = 224;
+const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Price of one whole bond token in fiat units: `price * 10^expo`.
+#[derive(Clone, Copy, Debug)]
+pub struct BondPrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+/// Reads the bond price configured on a mapping and rejects it unless it is
+/// fresh, positive and within the mapping's confidence tolerance.
+pub fn load_bond_price(
+    mapping: &BondCurrencyMapping,
+    price_oracle: &AccountInfo,
+    now: i64,
+) -> Result<BondPrice> {
+    require_keys_eq!(
+        price_oracle.key(),
+        mapping.price_oracle,
+        StablecoinError::InvalidOracleAccount
+    );
+
+    let price = match mapping.oracle_source() {
+        OracleSource::None => return err!(StablecoinError::OracleNotConfigured),
+        OracleSource::Pyth => read_pyth_price(price_oracle)?,
+        OracleSource::Manual => read_manual_price(price_oracle, &mapping.bond_mint)?,
+    };
+
+    validate_price(&price, now, mapping.max_price_age, mapping.max_confidence_bps)?;
+
+    Ok(price)
+}
+
+/// Reads the bond price for a coin through the mapping registered for its currency.
+pub fn load_coin_bond_price(
+    factory: &Factory,
+    sovereign_coin: &SovereignCoin,
+    price_oracle: &AccountInfo,
+    now: i64,
+) -> Result<BondPrice> {
+    let mapping = factory
+        .find_bond_mapping(sovereign_coin.fiat_currency_code())
+        .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+    require_keys_eq!(
+        mapping.bond_mint,
+        sovereign_coin.bond_mint,
+        StablecoinError::InvalidBondMint
+    );
+
+    load_bond_price(mapping, price_oracle, now)
+}
+
+/// Reads an FX rate account and rejects it unless it is fresh, positive and
+/// within its confidence tolerance.
+pub fn load_fx_rate(fx_rate: &FxRate, now: i64) -> Result<BondPrice> {
+    let price = BondPrice {
+        price: fx_rate.price,
+        conf: fx_rate.conf,
+        expo: fx_rate.expo,
+        publish_time: fx_rate.publish_time,
+    };
+
+    validate_price(&price, now, fx_rate.max_price_age, fx_rate.max_confidence_bps)?;
+
+    Ok(price)
+}
+
+fn read_pyth_price(price_oracle: &AccountInfo) -> Result<BondPrice> {
+    let data = price_oracle.try_borrow_data()?;
+    require!(
+        data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN,
+        StablecoinError::InvalidOracleAccount
+    );
+
+    let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    require!(
+        read_u32(0) == PYTH_MAGIC
+            && read_u32(4) == PYTH_VERSION_2
+            && read_u32(8) == PYTH_ACCOUNT_TYPE_PRICE,
+        StablecoinError::InvalidOracleAccount
+    );
+
+    // Only an aggregate that is currently trading is usable
+    require!(
+        read_u32(PYTH_AGG_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+        StablecoinError::InvalidOraclePrice
+    );
+
+    Ok(BondPrice {
+        price: read_u64(PYTH_AGG_PRICE_OFFSET) as i64,
+        conf: read_u64(PYTH_AGG_CONF_OFFSET),
+        expo: read_u32(PYTH_EXPO_OFFSET) as i32,
+        publish_time: read_u64(PYTH_TIMESTAMP_OFFSET) as i64,
+    })
+}
+
+fn read_manual_price(price_oracle: &AccountInfo, bond_mint: &Pubkey) -> Result<BondPrice> {
+    require_keys_eq!(
+        *price_oracle.owner,
+        crate::ID,
+        StablecoinError::InvalidOracleAccount
+    );
+    let manual_price = ManualPrice::try_deserialize(&mut &price_oracle.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        manual_price.bond_mint,
+        *bond_mint,
+        StablecoinError::InvalidOracleAccount
+    );
+
+    Ok(BondPrice {
+        price: manual_price.price,
+        conf: manual_price.conf,
+        expo: manual_price.expo,
+        publish_time: manual_price.publish_time,
+    })
+}
+
+pub fn validate_price(price: &BondPrice, now: i64, max_age: u32, max_confidence_bps: u16) -> Result<()> {
+    require!(price.price > 0, StablecoinError::InvalidOraclePrice);
+
+    // Prices from the future are as untrustworthy as old ones
+    let age = now
+        .checked_sub(price.publish_time)
+        .ok_or(StablecoinError::ArithmeticOverflow)?;
+    require!(
+        age >= 0 && age <= max_age as i64,
+        StablecoinError::StaleOraclePrice
+    );
+
+    // conf / price <= max_confidence_bps / 10_000
+    let conf_scaled = (price.conf as u128)
+        .checked_mul(10_000)
+        .ok_or(StablecoinError::ArithmeticOverflow)?;
+    let max_conf_scaled = (price.price as u128)
+        .checked_mul(max_confidence_bps as u128)
+        .ok_or(StablecoinError::ArithmeticOverflow)?;
+    require!(
+        conf_scaled <= max_conf_scaled,
+        StablecoinError::OracleConfidenceTooWide
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{normalize_amount, scaled_amount, Factory, FeeBreakdown, StablecoinError};
+
+/// Fiat owed for a redemption, at one unit of fiat per coin with the interest
+/// accrued up to settlement.
+pub struct RedemptionPayout {
+    pub fiat_paid: u64,              // To the holder, after fees
+    pub fiat_protocol_fee: u64,      // Insurance share included
+    pub insurance_fee: u64,          // Part of the protocol fee sent to the insurance vault
+    pub fiat_issuer_fee: u64,
+}
+
+impl RedemptionPayout {
+    pub fn new(
+        factory: &Factory,
+        fees: &FeeBreakdown,
+        mint_info: &AccountInfo,
+        coin_decimals: u8,
+        fiat_decimals: u8,
+        now: i64,
+    ) -> Result<Self> {
+        let to_fiat = |amount: u64| -> Result<u64> {
+            let value = scaled_amount(mint_info, amount, now)?;
+            Ok(normalize_amount(value, coin_decimals, fiat_decimals)
+                .ok_or(StablecoinError::ArithmeticOverflow)?)
+        };
+        let fiat_protocol_fee = to_fiat(fees.protocol_fee)?;
+        Ok(Self {
+            fiat_paid: to_fiat(fees.net_amount)?,
+            fiat_protocol_fee,
+            insurance_fee: insurance_share(factory, fiat_protocol_fee),
+            fiat_issuer_fee: to_fiat(fees.issuer_fee)?,
+        })
+    }
+
+    /// Fiat leaving the reserve, fees included.
+    pub fn total(&self) -> u64 {
+        self.fiat_paid + self.fiat_protocol_fee + self.fiat_issuer_fee
+    }
+
+    /// Pairs each payee with what it is owed, leaving out anyone owed nothing.
+    /// The insurance vault may only be missing while there is no insurance fee.
+    pub fn transfers<'info>(
+        &self,
+        holder: AccountInfo<'info>,
+        treasury: AccountInfo<'info>,
+        insurance_vault: Option<AccountInfo<'info>>,
+        issuer: AccountInfo<'info>,
+    ) -> Result<Vec<(AccountInfo<'info>, u64)>> {
+        require!(
+            insurance_vault.is_some() || self.insurance_fee == 0,
+            StablecoinError::InsuranceVaultRequired
+        );
+        Ok([
+            (Some(holder), self.fiat_paid),
+            (Some(treasury), self.fiat_protocol_fee - self.insurance_fee),
+            (insurance_vault, self.insurance_fee),
+            (Some(issuer), self.fiat_issuer_fee),
+        ]
+        .into_iter()
+        .filter_map(|(payee, amount)| payee.filter(|_| amount > 0).map(|payee| (payee, amount)))
+        .collect())
+    }
+}
+
+/// The factory's insurance share of a protocol fee, rounded down.
+pub fn insurance_share(factory: &Factory, protocol_fee: u64) -> u64 {
+    (protocol_fee as u128 * factory.insurance_fee_share_bps as u128 / 10_000) as u64
+}
@@ -1,6 +1,154 @@
+use crate::{BondPrice, SurplusPolicy};
+
 pub fn calculate_required_reserve(base: u8, ordinal: u8, multiplier: u8) -> u8 {
     // Formula: base + (ordinal - 1) * (multiplier / 9)
     base.saturating_add(
         ((ordinal.saturating_sub(1)) as u16 * multiplier as u16 * 10 / 9) as u8
     )
-}
\ No newline at end of file
+}
+
+/// Value of `bond_amount` bond base units expressed in fiat token base units.
+/// Rounds down so the reserve is never overstated.
+pub fn calculate_bond_value(
+    bond_amount: u64,
+    bond_decimals: u8,
+    fiat_decimals: u8,
+    price: &BondPrice,
+) -> Option<u64> {
+    // bond_amount * price * 10^(expo + fiat_decimals - bond_decimals)
+    let scale = price.expo as i64 + fiat_decimals as i64 - bond_decimals as i64;
+    let value = (bond_amount as u128).checked_mul(u128::try_from(price.price).ok()?)?;
+    let value = if scale >= 0 {
+        value.checked_mul(10u128.checked_pow(u32::try_from(scale).ok()?)?)?
+    } else {
+        value / 10u128.checked_pow(u32::try_from(-scale).ok()?)?
+    };
+    u64::try_from(value).ok()
+}
+
+/// Total reserve NAV: fiat reserve plus the fiat value of the bond holdings.
+pub fn calculate_reserve_nav(fiat_amount: u64, bond_value: u64) -> Option<u64> {
+    fiat_amount.checked_add(bond_value)
+}
+
+/// Converts a token amount between two decimal precisions, rounding down.
+pub fn normalize_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Option<u64> {
+    let value = if to_decimals >= from_decimals {
+        (amount as u128).checked_mul(10u128.checked_pow((to_decimals - from_decimals) as u32)?)?
+    } else {
+        amount as u128 / 10u128.checked_pow((from_decimals - to_decimals) as u32)?
+    };
+    u64::try_from(value).ok()
+}
+
+/// Converts `amount` at `price` (quote units per base unit), or at its inverse
+/// when `inverse` is set, between two decimal precisions. Rounds down.
+pub fn convert_at_price(
+    amount: u64,
+    from_decimals: u8,
+    to_decimals: u8,
+    price: &BondPrice,
+    inverse: bool,
+) -> Option<u64> {
+    // Forward: amount * price * 10^(expo + to - from)
+    // Inverse: amount * 10^(-expo + to - from) / price
+    let expo = if inverse { -(price.expo as i64) } else { price.expo as i64 };
+    let scale = expo + to_decimals as i64 - from_decimals as i64;
+    let price_value = u128::try_from(price.price).ok()?;
+    let (mut numerator, mut denominator) = if inverse {
+        (amount as u128, price_value)
+    } else {
+        ((amount as u128).checked_mul(price_value)?, 1)
+    };
+    let factor = 10u128.checked_pow(u32::try_from(scale.unsigned_abs()).ok()?)?;
+    if scale >= 0 {
+        numerator = numerator.checked_mul(factor)?;
+    } else {
+        denominator = denominator.checked_mul(factor)?;
+    }
+    let value = numerator / denominator;
+    u64::try_from(value).ok()
+}
+
+/// Whether a fiat reserve of `fiat_amount` covers `required_percentage` of a
+/// coin `supply`, comparing both at the coin's precision.
+pub fn meets_fiat_reserve(
+    fiat_amount: u64,
+    fiat_decimals: u8,
+    supply: u64,
+    coin_decimals: u8,
+    required_percentage: u8,
+) -> Option<bool> {
+    let fiat = normalize_amount(fiat_amount, fiat_decimals, coin_decimals)? as u128;
+    Some(fiat * 100 >= supply as u128 * required_percentage as u128)
+}
+
+/// Fiat, at the fiat's precision, that `fiat_amount` falls short of
+/// `required_percentage` of a coin `supply` by. Rounds up, so it is zero
+/// exactly when meets_fiat_reserve holds.
+pub fn fiat_reserve_deficit(
+    fiat_amount: u64,
+    fiat_decimals: u8,
+    supply: u64,
+    coin_decimals: u8,
+    required_percentage: u8,
+) -> Option<u64> {
+    let required = (supply as u128).checked_mul(required_percentage as u128)?;
+    let required = if fiat_decimals >= coin_decimals {
+        required.checked_mul(10u128.checked_pow((fiat_decimals - coin_decimals) as u32)?)?.div_ceil(100)
+    } else {
+        required.div_ceil(100u128.checked_mul(10u128.checked_pow((coin_decimals - fiat_decimals) as u32)?)?)
+    };
+    Some(u64::try_from(required).ok()?.saturating_sub(fiat_amount))
+}
+
+/// `numerator / denominator` in basis points, or zero when there is no denominator.
+pub fn ratio_bps(numerator: u64, denominator: u64) -> Option<u64> {
+    if denominator == 0 {
+        return Some(0);
+    }
+    let ratio = (numerator as u128).checked_mul(10_000)? / denominator as u128;
+    Some(u64::try_from(ratio).unwrap_or(u64::MAX))
+}
+
+/// Difference found between a cached reserve amount and its token balance.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Reconciliation {
+    pub surplus: u64,
+    pub shortfall: u64,
+}
+
+/// Brings a cached reserve amount (plus any yield set aside in the same token
+/// account) in line with the actual balance. Surplus is assigned per `policy`;
+/// a shortfall is absorbed by accrued yield first, then by the reserve itself.
+pub fn reconcile_reserve(
+    cached: &mut u64,
+    protocol_owned: &mut u64,
+    accrued_yield: &mut u64,
+    actual: u64,
+    policy: SurplusPolicy,
+) -> Option<Reconciliation> {
+    let expected = cached.checked_add(*accrued_yield)?;
+    let mut result = Reconciliation::default();
+    
+    if actual > expected {
+        result.surplus = actual - expected;
+        match policy {
+            SurplusPolicy::ProtocolOwned => {
+                *cached = cached.checked_add(result.surplus)?;
+                *protocol_owned = protocol_owned.checked_add(result.surplus)?;
+            }
+            SurplusPolicy::Yield => {
+                *accrued_yield = accrued_yield.checked_add(result.surplus)?;
+            }
+        }
+    } else if actual < expected {
+        result.shortfall = expected - actual;
+        let from_yield = result.shortfall.min(*accrued_yield);
+        *accrued_yield -= from_yield;
+        *cached = cached.checked_sub(result.shortfall - from_yield)?;
+        *protocol_owned = (*protocol_owned).min(*cached);
+    }
+    
+    Some(result)
+}
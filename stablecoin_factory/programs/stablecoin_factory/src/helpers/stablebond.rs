@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use stablebond_sdk::accounts::{Bond, Issuance};
+use stablebond_sdk::find_issuance_pda;
+use stablebond_sdk::types::{Discriminator, IssuanceStatus};
+
+use crate::StablecoinError;
+
+/// Deserializes a Stablebond `Bond` account, checking owner and discriminator.
+pub fn load_bond(bond_account: &AccountInfo) -> Result<Bond> {
+    require_keys_eq!(*bond_account.owner, stablebond_sdk::ID, StablecoinError::InvalidStablebondAccount);
+    let bond = Bond::from_bytes(&bond_account.try_borrow_data()?)
+        .map_err(|_| error!(StablecoinError::InvalidStablebondAccount))?;
+    require!(bond.discriminator == Discriminator::Bond, StablecoinError::InvalidStablebondAccount);
+    Ok(bond)
+}
+
+/// Deserializes the bond's current `Issuance`, which must sit at the PDA for
+/// the issuance number recorded on the bond.
+pub fn load_current_issuance(bond_account: &AccountInfo, bond: &Bond, issuance_account: &AccountInfo) -> Result<Issuance> {
+    let (expected, _) = find_issuance_pda(bond_account.key(), bond.issuance_number);
+    require_keys_eq!(issuance_account.key(), expected, StablecoinError::InvalidStablebondAccount);
+    require_keys_eq!(*issuance_account.owner, stablebond_sdk::ID, StablecoinError::InvalidStablebondAccount);
+    let issuance = Issuance::from_bytes(&issuance_account.try_borrow_data()?)
+        .map_err(|_| error!(StablecoinError::InvalidStablebondAccount))?;
+    require!(issuance.discriminator == Discriminator::Issuance, StablecoinError::InvalidStablebondAccount);
+    Ok(issuance)
+}
+
+/// True once the issuance is marked matured or has run its full length.
+pub fn is_matured(issuance: &Issuance, now: i64) -> bool {
+    if issuance.status == IssuanceStatus::Matured {
+        return true;
+    }
+    issuance.status == IssuanceStatus::Started
+        && issuance.actual_start_datetime > 0
+        && now >= issuance.actual_start_datetime.saturating_add(issuance.length_in_seconds)
+}
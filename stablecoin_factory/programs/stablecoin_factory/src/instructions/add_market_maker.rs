@@ -0,0 +1,60 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddMarketMaker<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MarketMaker::INIT_SPACE,
+        seeds = [b"market_maker", sovereign_coin.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub market_maker: Box<Account<'info, MarketMaker>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl AddMarketMaker<'_> {
+    pub fn handler(ctx: Context<Self>, wallet: Pubkey) -> Result<()> {
+        let clock = Clock::get()?;
+        
+        let market_maker = &mut ctx.accounts.market_maker;
+        market_maker.bump = ctx.bumps.market_maker;
+        market_maker.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        market_maker.wallet = wallet;
+        market_maker.added_at = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(MarketMakerUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: market_maker.sovereign_coin,
+            wallet,
+            added: true,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddToAllowlist<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PrimaryMarketAllowlist::INIT_SPACE,
+        seeds = [b"primary_market_allowlist", sovereign_coin.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Box<Account<'info, PrimaryMarketAllowlist>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl AddToAllowlist<'_> {
+    pub fn handler(ctx: Context<Self>, wallet: Pubkey) -> Result<()> {
+        let clock = Clock::get()?;
+        
+        let allowlist_entry = &mut ctx.accounts.allowlist_entry;
+        allowlist_entry.bump = ctx.bumps.allowlist_entry;
+        allowlist_entry.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        allowlist_entry.wallet = wallet;
+        allowlist_entry.added_at = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(AllowlistUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: allowlist_entry.sovereign_coin,
+            wallet,
+            added: true,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
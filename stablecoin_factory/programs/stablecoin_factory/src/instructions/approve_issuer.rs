@@ -0,0 +1,52 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveIssuer<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"issuer_profile", issuer_profile.issuer.as_ref()],
+        bump = issuer_profile.bump,
+    )]
+    pub issuer_profile: Box<Account<'info, IssuerProfile>>,
+}
+
+impl ApproveIssuer<'_> {
+    /// Approves the issuer for `coin_quota` coins in total. Also used to change
+    /// the quota or reinstate a revoked issuer.
+    pub fn handler(ctx: Context<Self>, coin_quota: u32) -> Result<()> {
+        let clock = Clock::get()?;
+        let issuer_profile = &mut ctx.accounts.issuer_profile;
+        issuer_profile.kyc_status = KycStatus::Approved;
+        issuer_profile.coin_quota = coin_quota;
+        issuer_profile.revoked = false;
+        issuer_profile.approved_at = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(IssuerApprovedEvent {
+            authority: ctx.accounts.authority.key(),
+            issuer: issuer_profile.issuer,
+            coin_quota,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+use anchor_lang::solana_program::program_option::COption;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BeginWindDown<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    // Nobody may mint into the snapshot behind the program's back
+    #[account(
+        constraint = mint.mint_authority == COption::Some(sovereign_coin.key()) @ StablecoinError::MintAuthorityNotDelegated,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl BeginWindDown<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        require!(!sovereign_coin.winding_down, StablecoinError::SovereignCoinWindingDown);
+        
+        // Freeze minting for good; unpause refuses to clear it from here on
+        sovereign_coin.winding_down = true;
+        sovereign_coin.paused |= PAUSE_MINT;
+        
+        // Every holder settles against this snapshot, so payouts do not
+        // depend on the order in which holders redeem. Accrued yield is not
+        // theirs and stays behind for the coin's Treasurer to withdraw.
+        sovereign_coin.wind_down_started_at = clock.unix_timestamp;
+        sovereign_coin.wind_down_supply = ctx.accounts.mint.supply;
+        sovereign_coin.wind_down_fiat = sovereign_coin.backing_fiat(ctx.accounts.fiat_reserve.amount);
+        sovereign_coin.wind_down_bond = sovereign_coin.backing_bond(ctx.accounts.bond_holding.amount);
+        
+        // Emit event
+        emit_cpi!(WindDownStartedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            supply: sovereign_coin.wind_down_supply,
+            fiat_reserve_balance: sovereign_coin.wind_down_fiat,
+            bond_holding_balance: sovereign_coin.wind_down_bond,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
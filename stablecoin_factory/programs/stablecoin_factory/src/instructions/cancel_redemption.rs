@@ -0,0 +1,90 @@
+use super::*;
+use spl_token_2022::onchain::invoke_transfer_checked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelRedemption<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption_ticket", sovereign_coin.key().as_ref(), &ticket.id.to_le_bytes()],
+        bump = ticket.bump,
+        has_one = owner,
+        constraint = ticket.status == TicketStatus::Queued @ StablecoinError::TicketNotQueued,
+    )]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", sovereign_coin.key().as_ref()],
+        bump,
+    )]
+    pub redemption_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub owner_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelRedemption<'info> {
+    /// Returns the escrowed coins. A ticket at the front of the queue is closed
+    /// straight away; one further back stays as Cancelled until the queue
+    /// reaches it, so the settlement order has no gaps.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        let amount = ctx.accounts.ticket.amount;
+        
+        let signer_seeds = sovereign_coin.signer_seeds();
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.redemption_escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.owner_coin_account.to_account_info(),
+            sovereign_coin.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&signer_seeds],
+        )?;
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.queued_redemptions = sovereign_coin.queued_redemptions.saturating_sub(amount);
+        
+        let ticket = &mut ctx.accounts.ticket;
+        ticket.status = TicketStatus::Cancelled;
+        if ticket.id == sovereign_coin.next_settle_ticket_id {
+            sovereign_coin.next_settle_ticket_id += 1;
+            ticket.close(ctx.accounts.owner.to_account_info())?;
+        }
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(RedemptionCancelledEvent {
+            owner: ctx.accounts.owner.key(),
+            sovereign_coin: sovereign_coin.key(),
+            ticket: ctx.accounts.ticket.key(),
+            id: ctx.accounts.ticket.id,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
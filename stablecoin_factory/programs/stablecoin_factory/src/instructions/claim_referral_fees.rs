@@ -0,0 +1,91 @@
+use super::*;
+use spl_token_2022::onchain::invoke_transfer_checked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    pub wallet: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"referrer", sovereign_coin.key().as_ref(), wallet.key().as_ref()],
+        bump = referrer.bump,
+        has_one = wallet,
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+    
+    #[account(
+        mut,
+        seeds = [b"referral_escrow", sovereign_coin.key().as_ref()],
+        bump,
+    )]
+    pub referral_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimReferralFees<'info> {
+    /// Pays out everything the referrer has accrued. Accounts required by the
+    /// compliance transfer hook, if any, go in remaining_accounts.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let amount = ctx.accounts.referrer.accrued;
+        require!(amount > 0, StablecoinError::NothingToClaim);
+        
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&*ctx.accounts.factory.load()?, PAUSE_REDEEM)?;
+        let signer_seeds = sovereign_coin.signer_seeds();
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.referral_escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination.to_account_info(),
+            sovereign_coin.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&signer_seeds],
+        )?;
+        
+        let referrer = &mut ctx.accounts.referrer;
+        referrer.accrued = 0;
+        referrer.total_claimed = referrer.total_claimed
+            .checked_add(amount)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(ReferralFeesClaimedEvent {
+            wallet: referrer.wallet,
+            sovereign_coin: referrer.sovereign_coin,
+            destination: ctx.accounts.destination.key(),
+            amount,
+            total_claimed: referrer.total_claimed,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSovereignCoin<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.authority == authority.key(),
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(constraint = mint.supply == 0 @ StablecoinError::SupplyNotZero)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(address = sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint)]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    // Rounding dust left after settlement goes to the protocol treasury
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::authority = factory.load()?.treasury,
+    )]
+    pub treasury_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = bond_token_mint,
+        token::authority = factory.load()?.treasury,
+    )]
+    pub treasury_bond_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub fiat_token_program: Interface<'info, TokenInterface>,
+    pub bond_token_program: Interface<'info, TokenInterface>,
+}
+
+impl CloseSovereignCoin<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        require!(sovereign_coin.winding_down, StablecoinError::SovereignCoinNotWindingDown);
+        
+        let signer_seeds = sovereign_coin.signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        
+        // Sweep any dust, then close both reserve accounts
+        let fiat_swept = ctx.accounts.fiat_reserve.amount;
+        if fiat_swept > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.fiat_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.fiat_reserve.to_account_info(),
+                        mint: ctx.accounts.fiat_token_mint.to_account_info(),
+                        to: ctx.accounts.treasury_fiat_account.to_account_info(),
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                fiat_swept,
+                ctx.accounts.fiat_token_mint.decimals,
+            )?;
+        }
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.fiat_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.fiat_reserve.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: sovereign_coin.to_account_info(),
+            },
+            signer,
+        ))?;
+        
+        let bond_swept = ctx.accounts.bond_holding.amount;
+        if bond_swept > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.bond_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.bond_holding.to_account_info(),
+                        mint: ctx.accounts.bond_token_mint.to_account_info(),
+                        to: ctx.accounts.treasury_bond_account.to_account_info(),
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                bond_swept,
+                ctx.accounts.bond_token_mint.decimals,
+            )?;
+        }
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.bond_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.bond_holding.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: sovereign_coin.to_account_info(),
+            },
+            signer,
+        ))?;
+        
+        // Only coins that were finalized were ever counted
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        if sovereign_coin.finalized {
+            factory.total_sovereign_coins = factory.total_sovereign_coins.checked_sub(1)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+        }
+        
+        // Release the coin's hold on its bond mint
+        if let Some(coin_count) = factory
+            .find_registered_mapping_mut(&sovereign_coin.target_fiat_currency)
+            .and_then(|mapping| mapping.coin_count_mut(&sovereign_coin.bond_mint))
+        {
+            *coin_count = coin_count.checked_sub(1)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+        }
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(SovereignCoinClosedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            fiat_swept,
+            bond_swept,
+            total_sovereign_coins: factory.total_sovereign_coins,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
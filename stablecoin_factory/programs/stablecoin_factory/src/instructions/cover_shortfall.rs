@@ -0,0 +1,119 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CoverShortfall<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::Treasurer, &treasurer.key()) @ StablecoinError::Unauthorized
+    )]
+    pub treasurer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: Signing PDA that owns every insurance vault
+    #[account(
+        seeds = [b"insurance", factory.key().as_ref()],
+        bump,
+    )]
+    pub insurance_authority: UncheckedAccount<'info>,
+    
+    // Vault for the coin's currency
+    #[account(
+        mut,
+        seeds = [b"insurance_vault", sovereign_coin.fiat_currency_code()],
+        bump,
+    )]
+    pub insurance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        address = fiat_reserve.mint,
+        constraint = insurance_vault.mint == fiat_token_mint.key() @ StablecoinError::InsuranceVaultMintMismatch,
+    )]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl CoverShortfall<'_> {
+    /// Moves fiat from the currency's insurance vault into the coin's reserve,
+    /// where it backs the supply like any other fiat. Only a reserve below its
+    /// required fiat ratio can be covered, and only up to that ratio.
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let clock = Clock::get()?;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&*ctx.accounts.factory.load()?, PAUSE_REDEEM)?;
+        let supply = scaled_amount(&ctx.accounts.mint.to_account_info(), ctx.accounts.mint.supply, clock.unix_timestamp)?;
+        let deficit = fiat_reserve_deficit(
+            sovereign_coin.backing_fiat(ctx.accounts.fiat_reserve.amount),
+            ctx.accounts.fiat_token_mint.decimals,
+            supply,
+            sovereign_coin.decimals,
+            sovereign_coin.required_reserve_percentage,
+        )
+        .ok_or(StablecoinError::ArithmeticOverflow)?;
+        require!(deficit > 0, StablecoinError::NoShortfall);
+        let amount = amount.min(deficit);
+        require!(ctx.accounts.insurance_vault.amount >= amount, StablecoinError::InsufficientInsuranceFund);
+        
+        let factory_key = ctx.accounts.factory.key();
+        let signer_seeds: &[&[u8]] = &[b"insurance", factory_key.as_ref(), &[ctx.bumps.insurance_authority]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.insurance_vault.to_account_info(),
+                    mint: ctx.accounts.fiat_token_mint.to_account_info(),
+                    to: ctx.accounts.fiat_reserve.to_account_info(),
+                    authority: ctx.accounts.insurance_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+            ctx.accounts.fiat_token_mint.decimals,
+        )?;
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.fiat_amount = sovereign_coin.fiat_amount
+            .checked_add(amount)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Emit event
+        emit_cpi!(ShortfallCoveredEvent {
+            treasurer: ctx.accounts.treasurer.key(),
+            sovereign_coin: sovereign_coin.key(),
+            insurance_vault: ctx.accounts.insurance_vault.key(),
+            amount,
+            fund_balance: ctx.accounts.insurance_vault.amount - amount,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
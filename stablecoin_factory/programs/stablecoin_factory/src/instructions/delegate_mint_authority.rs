@@ -0,0 +1,56 @@
+use super::*;
+use anchor_spl::token_interface::{set_authority, SetAuthority};
+use spl_token_2022::instruction::AuthorityType;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DelegateMintAuthority<'info> {
+    // The wallet still holding the mint authority of a coin set up before
+    // setup gave it to the coin PDA
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = authority,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl DelegateMintAuthority<'_> {
+    /// Hands the mint authority of a coin set up by an older program version
+    /// to the coin PDA, so program paths such as flash_mint can issue coins.
+    /// There is no way back.
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        
+        set_authority(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.authority.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            AuthorityType::MintTokens,
+            Some(sovereign_coin.key()),
+        )?;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(MintAuthorityDelegatedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            mint: ctx.accounts.mint.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
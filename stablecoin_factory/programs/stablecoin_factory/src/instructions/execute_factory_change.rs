@@ -0,0 +1,60 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteFactoryChange<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"pending_change", factory.key().as_ref(), &pending_change.id.to_le_bytes()],
+        bump = pending_change.bump,
+        has_one = factory,
+        has_one = queued_by,
+    )]
+    pub pending_change: Box<Account<'info, PendingChange>>,
+    
+    /// CHECK: Rent refund destination, checked against the pending change
+    #[account(mut)]
+    pub queued_by: UncheckedAccount<'info>,
+}
+
+impl ExecuteFactoryChange<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let pending_change = &ctx.accounts.pending_change;
+        require!(clock.unix_timestamp >= pending_change.eta, StablecoinError::TimelockNotElapsed);
+        
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        pending_change.change.apply(&mut factory)?;
+        
+        // Emit event
+        emit_cpi!(FactoryChangeExecutedEvent {
+            authority: ctx.accounts.authority.key(),
+            factory: ctx.accounts.factory.key(),
+            pending_change: pending_change.key(),
+            id: pending_change.id,
+            change: pending_change.change.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{spl_pod::optional_keys::OptionalNonZeroPubkey, spl_token_metadata_interface::state::TokenMetadata};
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -10,7 +12,7 @@ pub struct FinalizeSetup<'info> {
     
     #[account(
         mut,
-        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol.as_ref()],
+        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol_seed()],
         bump = sovereign_coin.bump,
         constraint = sovereign_coin.authority == authority.key()
     )]
@@ -19,18 +21,22 @@ pub struct FinalizeSetup<'info> {
     #[account(
         mut, 
         seeds = [b"factory"],
-        bump = factory.bump,
+        bump = factory.load()?.bump,
     )]
-    pub factory: Box<Account<'info, Factory>>,
+    pub factory: AccountLoader<'info, Factory>,
     
+    #[account(mut, address = sovereign_coin.mint)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
     
-    // Metadata-related accounts
+    // Metaplex metadata accounts, only for MetadataStandard::Metaplex
     /// CHECK: Will be created via CPI to token metadata program
     #[account(mut)]
-    pub metadata: UncheckedAccount<'info>,
+    pub metadata: Option<UncheckedAccount<'info>>,
     
-    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    
+    // Only for MetadataStandard::Token2022, which writes metadata on the mint
+    pub token_program: Option<Program<'info, Token2022>>,
     
     // System programs
     pub system_program: Program<'info, System>,
@@ -40,6 +46,7 @@ pub struct FinalizeSetup<'info> {
 impl FinalizeSetup<'_> {
     pub fn handler(ctx: Context<Self>) -> Result<()> {
         let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&*ctx.accounts.factory.load()?, PAUSE_MINT)?;
         
         // Extract data from sovereign_coin for metadata
         let name = std::str::from_utf8(
@@ -66,36 +73,97 @@ impl FinalizeSetup<'_> {
                 .collect::<Vec<u8>>()
         ).unwrap_or("").to_string();
         
-        // Create metadata
-        let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
-        let cpi_accounts = CreateMetadataAccountsV3 {
-            metadata: ctx.accounts.metadata.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            mint_authority: ctx.accounts.authority.to_account_info(),
-            payer: ctx.accounts.payer.to_account_info(),
-            update_authority: ctx.accounts.authority.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            rent: ctx.accounts.rent.to_account_info(),
-        };
-        
-        create_metadata_accounts_v3(
-            CpiContext::new(cpi_program, cpi_accounts),
-            DataV2 {
-                name: name.clone(),
-                symbol: symbol.clone(),
-                uri: uri.clone(),
-                seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
-                uses: None,
-            },
-            false,
-            true,
-            None,
-        )?;
+        match sovereign_coin.metadata_standard {
+            MetadataStandard::Metaplex => {
+                let metadata = ctx.accounts.metadata.as_ref()
+                    .ok_or(StablecoinError::MissingMetadataAccount)?;
+                let token_metadata_program = ctx.accounts.token_metadata_program.as_ref()
+                    .ok_or(StablecoinError::MissingMetadataAccount)?;
+                
+                // Create metadata; the coin PDA signs as mint authority
+                let cpi_program = token_metadata_program.to_account_info();
+                let cpi_accounts = CreateMetadataAccountsV3 {
+                    metadata: metadata.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    mint_authority: sovereign_coin.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    update_authority: ctx.accounts.authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                };
+                
+                create_metadata_accounts_v3(
+                    CpiContext::new_with_signer(cpi_program, cpi_accounts, &[&sovereign_coin.signer_seeds()]),
+                    DataV2 {
+                        name: name.clone(),
+                        symbol: symbol.clone(),
+                        uri: uri.clone(),
+                        seller_fee_basis_points: 0,
+                        creators: None,
+                        collection: None,
+                        uses: None,
+                    },
+                    false,
+                    true,
+                    None,
+                )?;
+            }
+            MetadataStandard::Token2022 => {
+                let token_program = ctx.accounts.token_program.as_ref()
+                    .ok_or(StablecoinError::MissingMetadataAccount)?;
+                
+                // The token program reallocs the mint for the metadata entry
+                // but cannot fund it, so top up rent first
+                let token_metadata = TokenMetadata {
+                    update_authority: OptionalNonZeroPubkey(ctx.accounts.authority.key()),
+                    mint: ctx.accounts.mint.key(),
+                    name: name.clone(),
+                    symbol: symbol.clone(),
+                    uri: uri.clone(),
+                    additional_metadata: vec![],
+                };
+                let mint_info = ctx.accounts.mint.to_account_info();
+                let new_len = mint_info.data_len()
+                    .checked_add(token_metadata.tlv_size_of()?)
+                    .ok_or(StablecoinError::ArithmeticOverflow)?;
+                let top_up = Rent::get()?.minimum_balance(new_len)
+                    .saturating_sub(mint_info.lamports());
+                if top_up > 0 {
+                    transfer(
+                        CpiContext::new(
+                            ctx.accounts.system_program.to_account_info(),
+                            Transfer {
+                                from: ctx.accounts.payer.to_account_info(),
+                                to: mint_info.clone(),
+                            },
+                        ),
+                        top_up,
+                    )?;
+                }
+                
+                token_interface::token_metadata_initialize(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        token_interface::TokenMetadataInitialize {
+                            token_program_id: token_program.to_account_info(),
+                            metadata: mint_info.clone(),
+                            update_authority: ctx.accounts.authority.to_account_info(),
+                            mint_authority: sovereign_coin.to_account_info(),
+                            mint: mint_info,
+                        },
+                        &[&sovereign_coin.signer_seeds()],
+                    ),
+                    name.clone(),
+                    symbol.clone(),
+                    uri.clone(),
+                )?;
+            }
+        }
         
         // Update factory stats
-        let factory = &mut ctx.accounts.factory;
+        ctx.accounts.sovereign_coin.finalized = true;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        let mut factory = ctx.accounts.factory.load_mut()?;
         factory.total_sovereign_coins = factory.total_sovereign_coins.checked_add(1)
             .ok_or(StablecoinError::ArithmeticOverflow)?;
         
@@ -120,6 +188,7 @@ impl FinalizeSetup<'_> {
             bond_mint: sovereign_coin.bond_mint,
             bond_account: sovereign_coin.bond_account,
             bond_rating: sovereign_coin.bond_rating,
+            metadata_standard: sovereign_coin.metadata_standard,
             timestamp: clock.unix_timestamp,
         });
         
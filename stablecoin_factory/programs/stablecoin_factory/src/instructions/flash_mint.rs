@@ -0,0 +1,127 @@
+use super::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::Discriminator;
+
+// Position of sovereign_coin in the FlashRepay accounts
+const FLASH_REPAY_SOVEREIGN_COIN_INDEX: usize = 2;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashMint<'info> {
+    pub borrower: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        mut,
+        constraint = mint.mint_authority == COption::Some(sovereign_coin.key()) @ StablecoinError::MintAuthorityNotDelegated,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub receiver: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: The instructions sysvar, used to find the matching flash_repay
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl FlashMint<'_> {
+    /// Mints `amount` to `receiver`. The transaction must call flash_repay for
+    /// the same coin after this instruction, which burns the amount plus the fee.
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&*ctx.accounts.factory.load()?, PAUSE_MINT)?;
+        require!(sovereign_coin.flash_mint_cap > 0, StablecoinError::FlashMintDisabled);
+        require!(amount <= sovereign_coin.flash_mint_cap, StablecoinError::FlashMintCapExceeded);
+        require!(sovereign_coin.flash_outstanding == 0, StablecoinError::FlashMintOutstanding);
+        
+        // Introspection only sees top-level instructions, so refuse CPI callers
+        require!(
+            get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT,
+            StablecoinError::FlashMintNotTopLevel
+        );
+        require_flash_repay(&ctx.accounts.instructions.to_account_info(), &sovereign_coin.key())?;
+        
+        let fee = (amount as u128 * sovereign_coin.flash_fee_bps as u128).div_ceil(10_000) as u64;
+        
+        let signer_seeds = sovereign_coin.signer_seeds();
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.receiver.to_account_info(),
+                    authority: sovereign_coin.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            amount,
+        )?;
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.flash_outstanding = amount;
+        sovereign_coin.flash_fee_due = fee;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(FlashMintEvent {
+            borrower: ctx.accounts.borrower.key(),
+            sovereign_coin: sovereign_coin.key(),
+            receiver: ctx.accounts.receiver.key(),
+            amount,
+            fee,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
+
+/// Fails unless a later top-level instruction is this program's flash_repay
+/// for `sovereign_coin`.
+fn require_flash_repay(instructions: &AccountInfo, sovereign_coin: &Pubkey) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)? as usize;
+    let current = load_instruction_at_checked(current_index, instructions)?;
+    require_keys_eq!(current.program_id, crate::ID, StablecoinError::FlashMintNotTopLevel);
+    
+    let mut index = current_index + 1;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions) {
+        let is_repay = ix.program_id == crate::ID
+            && ix.data.get(..8) == Some(&crate::instruction::FlashRepay::DISCRIMINATOR[..])
+            && ix.accounts
+                .get(FLASH_REPAY_SOVEREIGN_COIN_INDEX)
+                .is_some_and(|meta| meta.pubkey == *sovereign_coin);
+        if is_repay {
+            return Ok(());
+        }
+        index += 1;
+    }
+    
+    err!(StablecoinError::FlashRepayMissing)
+}
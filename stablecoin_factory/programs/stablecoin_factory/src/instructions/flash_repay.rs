@@ -0,0 +1,98 @@
+use super::*;
+use spl_token_2022::onchain::invoke_transfer_checked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashRepay<'info> {
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    // Must stay at the index flash_mint looks for
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = factory.load()?.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FlashRepay<'info> {
+    /// Burns the outstanding flash mint and moves the fee to the treasury.
+    /// Accounts required by the compliance transfer hook, if any, go in
+    /// remaining_accounts.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let amount = ctx.accounts.sovereign_coin.flash_outstanding;
+        let fee = ctx.accounts.sovereign_coin.flash_fee_due;
+        require!(amount > 0, StablecoinError::NoFlashMintOutstanding);
+        
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.payer_coin_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        if fee > 0 {
+            invoke_transfer_checked(
+                &ctx.accounts.token_program.key(),
+                ctx.accounts.payer_coin_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.treasury_coin_account.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.remaining_accounts,
+                fee,
+                ctx.accounts.mint.decimals,
+                &[],
+            )?;
+        }
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.flash_outstanding = 0;
+        sovereign_coin.flash_fee_due = 0;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(FlashRepayEvent {
+            payer: ctx.accounts.payer.key(),
+            sovereign_coin: sovereign_coin.key(),
+            amount,
+            fee,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
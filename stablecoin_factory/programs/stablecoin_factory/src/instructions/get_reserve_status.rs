@@ -0,0 +1,144 @@
+use super::*;
+
+/// Proof-of-reserves snapshot returned through the transaction return data.
+/// All fiat-denominated values are in fiat token base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReserveStatus {
+    pub sovereign_coin: Pubkey,
+    pub mint: Pubkey,
+    pub total_supply: u64,                // Circulating supply read from the mint
+    pub scaled_supply: u64,               // Supply as displayed, with any accrued interest
+    pub fiat_reserve_balance: u64,        // Actual balance of fiat_reserve
+    pub bond_holding_balance: u64,        // Actual balance of bond_holding
+    pub bond_nav: u64,                    // Zero when no valid oracle price is available
+    pub reserve_nav: u64,                 // Backing fiat plus bond NAV, excluding accrued yield
+    pub collateral_ratio_bps: u64,        // Reserve NAV over supply, zero with no supply
+    pub fiat_reserve_ratio_bps: u64,      // Fiat reserve over supply, zero with no supply
+    pub required_reserve_ratio_bps: u64,  // Minimum fiat reserve ratio for the coin
+    pub insurance_fund_balance: u64,      // Currency's insurance vault, zero when not passed
+    pub flags: u8,                        // RESERVE_STATUS_* bits
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct GetReserveStatus<'info> {
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: Validated against the bond mapping's configured oracle when read
+    pub price_oracle: UncheckedAccount<'info>,
+    
+    #[account(address = sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint)]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        seeds = [b"insurance_vault", sovereign_coin.fiat_currency_code()],
+        bump,
+    )]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl GetReserveStatus<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<ReserveStatus> {
+        let clock = Clock::get()?;
+        let accounts = &ctx.accounts;
+        let sovereign_coin = &accounts.sovereign_coin;
+        
+        let total_supply = accounts.mint.supply;
+        let fiat_reserve_balance = accounts.fiat_reserve.amount;
+        let bond_holding_balance = accounts.bond_holding.amount;
+        let fiat_decimals = accounts.fiat_token_mint.decimals;
+        
+        let mut flags = 0u8;
+        
+        // A missing or unusable price only zeroes the bond NAV, so the view
+        // stays available to integrators while never overstating reserves
+        let price = load_coin_bond_price(
+            &*accounts.factory.load()?,
+            sovereign_coin,
+            &accounts.price_oracle.to_account_info(),
+            clock.unix_timestamp,
+        ).ok();
+        let bond_nav = match price {
+            Some(price) => {
+                flags |= RESERVE_STATUS_PRICE_VALID;
+                calculate_bond_value(
+                    sovereign_coin.backing_bond(bond_holding_balance),
+                    accounts.bond_token_mint.decimals,
+                    fiat_decimals,
+                    &price,
+                ).ok_or(StablecoinError::ArithmeticOverflow)?
+            }
+            None => 0,
+        };
+        let backing_fiat = sovereign_coin.backing_fiat(fiat_reserve_balance);
+        let reserve_nav = calculate_reserve_nav(backing_fiat, bond_nav)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Interest-bearing balances display more than the raw supply, and the
+        // reserves have to cover what holders see
+        let scaled_supply = scaled_amount(
+            &accounts.mint.to_account_info(),
+            total_supply,
+            clock.unix_timestamp,
+        )?;
+        
+        // Express supply in fiat token units so the ratios compare like with like
+        let supply_value = normalize_amount(scaled_supply, accounts.mint.decimals, fiat_decimals)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let collateral_ratio_bps = ratio_bps(reserve_nav, supply_value)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let fiat_reserve_ratio_bps = ratio_bps(backing_fiat, supply_value)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let required_reserve_ratio_bps = sovereign_coin.required_reserve_percentage as u64 * 100;
+        
+        if reserve_nav >= supply_value {
+            flags |= RESERVE_STATUS_FULLY_BACKED;
+        }
+        if supply_value == 0 || fiat_reserve_ratio_bps >= required_reserve_ratio_bps {
+            flags |= RESERVE_STATUS_FIAT_BUFFER_MET;
+        }
+        if sovereign_coin.fiat_amount.checked_add(sovereign_coin.accrued_yield_fiat) == Some(fiat_reserve_balance)
+            && sovereign_coin.bond_amount.checked_add(sovereign_coin.accrued_yield_bond) == Some(bond_holding_balance)
+        {
+            flags |= RESERVE_STATUS_CACHE_IN_SYNC;
+        }
+        
+        Ok(ReserveStatus {
+            sovereign_coin: sovereign_coin.key(),
+            mint: accounts.mint.key(),
+            total_supply,
+            scaled_supply,
+            fiat_reserve_balance,
+            bond_holding_balance,
+            bond_nav,
+            reserve_nav,
+            collateral_ratio_bps,
+            fiat_reserve_ratio_bps,
+            required_reserve_ratio_bps,
+            insurance_fund_balance: accounts.insurance_vault.as_ref().map_or(0, |vault| vault.amount),
+            flags,
+            timestamp: clock.unix_timestamp,
+        })
+    }
+}
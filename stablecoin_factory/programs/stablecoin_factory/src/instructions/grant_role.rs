@@ -0,0 +1,47 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        constraint = roles.has_role(Role::IssuerAdmin, &admin.key()) @ StablecoinError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"roles", roles.scope.as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+}
+
+impl GrantRole<'_> {
+    pub fn handler(ctx: Context<Self>, role: Role, holder: Pubkey) -> Result<()> {
+        let roles = &mut ctx.accounts.roles;
+        require_keys_neq!(holder, Pubkey::default(), StablecoinError::Unauthorized);
+        
+        // Pausing and unpausing must never sit with the same key
+        match role {
+            Role::Guardian => require_keys_neq!(holder, roles.issuer_admin, StablecoinError::GuardianIsAuthority),
+            Role::IssuerAdmin => require_keys_neq!(holder, roles.guardian, StablecoinError::GuardianIsAuthority),
+            _ => {}
+        }
+        
+        let old_holder = roles.get(role);
+        roles.set(role, holder);
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(RoleGrantedEvent {
+            admin: ctx.accounts.admin.key(),
+            scope: roles.scope,
+            role,
+            old_holder,
+            new_holder: holder,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
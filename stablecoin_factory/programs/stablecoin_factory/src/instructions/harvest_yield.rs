@@ -0,0 +1,140 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct HarvestYield<'info> {
+    // The rate is annualized from a single harvest period, so a caller free to
+    // pick the moment could inflate it
+    #[account(
+        constraint = coin_roles.has_role(Role::RateOracleUpdater, &rate_updater.key()) @ StablecoinError::Unauthorized
+    )]
+    pub rate_updater: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+        constraint = sovereign_coin.interest_bearing @ StablecoinError::NotInterestBearing,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: Validated against the bond mapping's configured oracle when read
+    pub price_oracle: UncheckedAccount<'info>,
+    
+    #[account(address = sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint)]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl HarvestYield<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let factory = ctx.accounts.factory.load()?;
+        ctx.accounts.sovereign_coin.require_not_paused(&factory, PAUSE_YIELD)?;
+        require!(!ctx.accounts.sovereign_coin.winding_down, StablecoinError::SovereignCoinWindingDown);
+        
+        let clock = Clock::get()?;
+        let accounts = &ctx.accounts;
+        let sovereign_coin = &accounts.sovereign_coin;
+        let elapsed = clock.unix_timestamp.saturating_sub(sovereign_coin.last_yield_harvest_at);
+        require!(elapsed > 0, StablecoinError::HarvestTooEarly);
+        
+        let price = load_coin_bond_price(
+            &factory,
+            sovereign_coin,
+            &accounts.price_oracle.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        let bond_decimals = accounts.bond_token_mint.decimals;
+        let fiat_decimals = accounts.fiat_token_mint.decimals;
+        let value_of = |fiat: u64, bond: u64| -> Result<u64> {
+            let bond_value = calculate_bond_value(bond, bond_decimals, fiat_decimals, &price)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+            Ok(calculate_reserve_nav(fiat, bond_value).ok_or(StablecoinError::ArithmeticOverflow)?)
+        };
+        
+        // Yield realized since the last harvest is what sync_reserves set aside
+        let realized_yield = value_of(sovereign_coin.accrued_yield_fiat, sovereign_coin.accrued_yield_bond)?;
+        let holder_share = factory.yield_share_holders as u128;
+        let holder_fiat = (sovereign_coin.accrued_yield_fiat as u128 * holder_share / 100) as u64;
+        let holder_bond = (sovereign_coin.accrued_yield_bond as u128 * holder_share / 100) as u64;
+        let holder_yield = value_of(holder_fiat, holder_bond)?;
+        
+        // Holders are owed the displayed supply, interest included
+        let scaled_supply = scaled_amount(
+            &accounts.mint.to_account_info(),
+            accounts.mint.supply,
+            clock.unix_timestamp,
+        )?;
+        let supply_value = normalize_amount(scaled_supply, accounts.mint.decimals, fiat_decimals)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let rate_bps = annualized_rate_bps(holder_yield, supply_value, elapsed)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        let signer_seeds = sovereign_coin.signer_seeds();
+        token_interface::interest_bearing_mint_update_rate(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token_interface::InterestBearingMintUpdateRate {
+                    token_program_id: accounts.token_program.to_account_info(),
+                    mint: accounts.mint.to_account_info(),
+                    rate_authority: sovereign_coin.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            rate_bps,
+        )?;
+        
+        // The holder share now backs the interest accruing on balances
+        let sovereign_coin_key = ctx.accounts.sovereign_coin.key();
+        let sovereign_coin: &mut SovereignCoin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.accrued_yield_fiat -= holder_fiat;
+        sovereign_coin.accrued_yield_bond -= holder_bond;
+        sovereign_coin.fiat_amount = sovereign_coin.fiat_amount
+            .checked_add(holder_fiat)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        sovereign_coin.bond_amount = sovereign_coin.bond_amount
+            .checked_add(holder_bond)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        sovereign_coin.holder_rate_bps = rate_bps;
+        sovereign_coin.last_yield_harvest_at = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(YieldHarvestedEvent {
+            sovereign_coin: sovereign_coin_key,
+            realized_yield,
+            holder_yield,
+            holder_fiat,
+            holder_bond,
+            scaled_supply,
+            elapsed,
+            rate_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
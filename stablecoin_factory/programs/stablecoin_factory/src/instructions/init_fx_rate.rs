@@ -0,0 +1,85 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(base_currency: String, quote_currency: String)]
+pub struct InitFxRate<'info> {
+    #[account(
+        mut,
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FxRate::INIT_SPACE,
+        seeds = [b"fx_rate", base_currency.as_bytes(), quote_currency.as_bytes()],
+        bump
+    )]
+    pub fx_rate: Box<Account<'info, FxRate>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl InitFxRate<'_> {
+    pub fn handler(
+        ctx: Context<Self>,
+        base_currency: String,
+        quote_currency: String,
+        expo: i32,
+        max_price_age: u32,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        // Both sides must be currencies coins can be issued in
+        let factory = ctx.accounts.factory.load()?;
+        require!(
+            factory.find_bond_mapping(base_currency.as_bytes()).is_some()
+                && factory.find_bond_mapping(quote_currency.as_bytes()).is_some(),
+            StablecoinError::NoBondMappingForCurrency
+        );
+        require!(base_currency != quote_currency, StablecoinError::InvalidFiatCurrency);
+        require!(max_confidence_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+        
+        // Price stays at zero, and is rejected by readers, until the first update
+        let fx_rate = &mut ctx.accounts.fx_rate;
+        fx_rate.bump = ctx.bumps.fx_rate;
+        fx_rate.base_currency = [0u8; 8];
+        fx_rate.base_currency[..base_currency.len()].copy_from_slice(base_currency.as_bytes());
+        fx_rate.quote_currency = [0u8; 8];
+        fx_rate.quote_currency[..quote_currency.len()].copy_from_slice(quote_currency.as_bytes());
+        fx_rate.price = 0;
+        fx_rate.conf = 0;
+        fx_rate.expo = expo;
+        fx_rate.publish_time = 0;
+        fx_rate.max_price_age = max_price_age;
+        fx_rate.max_confidence_bps = max_confidence_bps;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(FxRateInitializedEvent {
+            authority: ctx.accounts.authority.key(),
+            fx_rate: fx_rate.key(),
+            base_currency: fx_rate.base_currency,
+            quote_currency: fx_rate.quote_currency,
+            expo,
+            max_price_age,
+            max_confidence_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(fiat_currency: String)]
+pub struct InitInsuranceVault<'info> {
+    #[account(
+        mut,
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    /// CHECK: Signing PDA that owns every insurance vault
+    #[account(
+        seeds = [b"insurance", factory.key().as_ref()],
+        bump,
+    )]
+    pub insurance_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"insurance_vault", fiat_currency.as_bytes()],
+        bump,
+        token::mint = fiat_token_mint,
+        token::authority = insurance_authority,
+        token::token_program = token_program,
+    )]
+    pub insurance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitInsuranceVault<'_> {
+    /// Creates the backstop vault for one currency. It fills from the
+    /// factory's insurance share of protocol fees, or from direct transfers.
+    pub fn handler(ctx: Context<Self>, fiat_currency: String) -> Result<()> {
+        require!(
+            ctx.accounts.factory.load()?.find_bond_mapping(fiat_currency.as_bytes()).is_some(),
+            StablecoinError::NoBondMappingForCurrency
+        );
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(InsuranceVaultInitializedEvent {
+            authority: ctx.accounts.authority.key(),
+            fiat_currency,
+            insurance_vault: ctx.accounts.insurance_vault.key(),
+            fiat_token_mint: ctx.accounts.fiat_token_mint.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
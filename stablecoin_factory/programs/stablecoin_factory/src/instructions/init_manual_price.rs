@@ -0,0 +1,50 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct InitManualPrice<'info> {
+    #[account(
+        mut,
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ManualPrice::INIT_SPACE,
+        seeds = [b"manual_price", bond_token_mint.key().as_ref()],
+        bump
+    )]
+    pub manual_price: Box<Account<'info, ManualPrice>>,
+    
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl InitManualPrice<'_> {
+    pub fn handler(ctx: Context<Self>, expo: i32) -> Result<()> {
+        // Price stays at zero, and is rejected by readers, until the first update
+        let manual_price = &mut ctx.accounts.manual_price;
+        manual_price.bump = ctx.bumps.manual_price;
+        manual_price.bond_mint = ctx.accounts.bond_token_mint.key();
+        manual_price.price = 0;
+        manual_price.conf = 0;
+        manual_price.expo = expo;
+        manual_price.publish_time = 0;
+        
+        Ok(())
+    }
+}
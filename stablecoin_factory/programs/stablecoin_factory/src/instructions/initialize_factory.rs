@@ -9,11 +9,20 @@ pub struct InitializeFactory<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + Factory::INIT_SPACE,
+        space = Factory::LEN,
         seeds = [b"factory"],
         bump
     )]
-    pub factory: Box<Account<'info, Factory>>,
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Roles::INIT_SPACE,
+        seeds = [b"roles", factory.key().as_ref()],
+        bump
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
     
     pub system_program: Program<'info, System>,
 }
@@ -28,7 +37,8 @@ impl InitializeFactory<'_> {
         yield_share_issuer: u8,
         yield_share_holders: u8,
     ) -> Result<()> {
-        let factory = &mut ctx.accounts.factory;
+        let factory_key = ctx.accounts.factory.key();
+        let mut factory = ctx.accounts.factory.load_init()?;
         
         // Validate parameters
         require!(
@@ -40,9 +50,34 @@ impl InitializeFactory<'_> {
         factory.bump = bump;
         factory.authority = ctx.accounts.authority.key();
         factory.treasury = ctx.accounts.authority.key(); // Initially set treasury to authority
+        factory.paused = 0;
+        factory.coin_max_supply_cap = u64::MAX; // Unbounded until governance sets caps
+        factory.coin_mint_limit_cap = u64::MAX;
+        factory.coin_redeem_limit_cap = u64::MAX;
+        factory.timelock_delay = MIN_TIMELOCK_DELAY;
+        factory.next_change_id = 0;
+        factory.outflow_limit = u64::MAX; // Breaker disabled until governance configures it
+        factory.max_price_drop_bps = 0;
+        factory.breaker_flags = PAUSE_ALL;
+        factory.set_breaker_trip(BreakerTrip::None);
+        factory.breaker_tripped_at = 0;
+        factory.breaker_paused = 0;
+        factory.rebalance_band_bps = 0; // Rebalancing off until governance sets a band
+        factory.keeper_reward_bps = 0;
+        factory.insurance_fee_share_bps = 0;
+        factory.coin_creation_fee = 0;
+        factory.version = FACTORY_VERSION;
+        factory.reserved = [0u8; FACTORY_RESERVED_BYTES];
+        
+        // The creator starts with every role but guardian
+        ctx.accounts.factory_roles.init(
+            ctx.bumps.factory_roles,
+            factory_key,
+            ctx.accounts.authority.key(),
+        );
         
         factory.total_sovereign_coins = 0;
-        factory.total_supply_all_coins = 0;
+        factory.total_supply_all_coins = [0u8; 16];
         
         // Set bond rating ordinals according to whitepaper
         factory.bond_rating_ordinals = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
@@ -59,12 +94,20 @@ impl InitializeFactory<'_> {
         // Initialize with zero fees
         factory.mint_fee_bps = 0;
         factory.burn_fee_bps = 0;
+        factory.max_issuer_fee_bps = 0; // Issuers charge nothing until governance allows it
+        factory.swap_fee_bps = 0;
+        factory.referral_share_bps = 0;
+        
+        // Unaccounted reserve balances back the coin until changed
+        factory.surplus_policy = SurplusPolicy::ProtocolOwned as u8;
+
+        drop(factory);
 
         // Emit the initialization event
         let clock = Clock::get()?;
         emit_cpi!(FactoryInitializedEvent {
             authority: ctx.accounts.authority.key(),
-            factory: ctx.accounts.factory.key(),
+            factory: factory_key,
             min_fiat_reserve,
             bond_reserve_multiplier,
             yield_share_protocol,
@@ -1,4 +1,5 @@
 use super::*;
+use anchor_lang::system_program::{transfer, Transfer};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct SovereignCoinArgs {
@@ -18,10 +19,11 @@ pub struct InitSovereignCoin<'info> {
     pub authority: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"factory"],
-        bump = factory.bump,
+        bump = factory.load()?.bump,
     )]
-    pub factory: Box<Account<'info, Factory>>,
+    pub factory: AccountLoader<'info, Factory>,
     
     #[account(
         init,
@@ -32,6 +34,26 @@ pub struct InitSovereignCoin<'info> {
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
     
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Roles::INIT_SPACE,
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"issuer_profile", authority.key().as_ref()],
+        bump = issuer_profile.bump,
+    )]
+    pub issuer_profile: Box<Account<'info, IssuerProfile>>,
+    
+    /// CHECK: Receives the creation fee
+    #[account(mut, address = factory.load()?.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+    
     // External token mints for validation
     pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
     pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -48,28 +70,19 @@ impl InitSovereignCoin<'_> {
         require!(args.symbol.len() <= 8, StablecoinError::SymbolTooLong);
         require!(args.uri.len() <= 200, StablecoinError::UriTooLong);
         require!(args.fiat_currency.len() <= 8, StablecoinError::FiatCurrencyTooLong);
-        require!(args.fiat_currency.len() > 0, StablecoinError::InvalidFiatCurrency);
+        require!(!args.fiat_currency.is_empty(), StablecoinError::InvalidFiatCurrency);
+        
+        // New coins only exist to be minted
+        let factory = self.factory.load()?;
+        require!(!factory.is_paused(PAUSE_MINT), StablecoinError::FactoryPaused);
+        
+        // Only vetted issuers within their quota
+        self.issuer_profile.require_can_create()?;
         
         // Verify that the fiat currency exists in registered bond mappings
-        let factory = &self.factory;
-        let fiat_bytes = args.fiat_currency.as_bytes();
-        let mut mapping_found = false;
-        
-        for i in 0..factory.bond_mappings_count as usize {
-            let mapping = &factory.bond_mappings[i];
-            if mapping.active {
-                let mapping_currency = &mapping.fiat_currency;
-                // Check if currencies match (ignoring null bytes)
-                let mapping_len = mapping_currency.iter().take_while(|&&b| b != 0).count();
-                if mapping_len == fiat_bytes.len() {
-                    let mapping_prefix = &mapping_currency[..mapping_len];
-                    if mapping_prefix == fiat_bytes {
-                        mapping_found = true;
-                        break;
-                    }
-                }
-            }
-        }
+        let mapping_found = factory
+            .find_bond_mapping(args.fiat_currency.as_bytes())
+            .is_some();
         
         require!(mapping_found, StablecoinError::NoBondMappingForCurrency);
         
@@ -78,7 +91,25 @@ impl InitSovereignCoin<'_> {
     
     pub fn handler(ctx: Context<Self>, args: SovereignCoinArgs) -> Result<()> {
         // Validate inputs
-        Self::validate(&ctx.accounts, &args)?;
+        Self::validate(ctx.accounts, &args)?;
+        
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        
+        // Pay the creation fee, if any
+        let creation_fee = factory.coin_creation_fee;
+        if creation_fee > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                creation_fee,
+            )?;
+        }
+        ctx.accounts.issuer_profile.coins_created += 1;
         
         // Initialize the sovereign coin struct
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
@@ -107,28 +138,10 @@ impl InitSovereignCoin<'_> {
         sovereign_coin.target_fiat_currency[..fiat_bytes.len()].copy_from_slice(fiat_bytes);
         
         // Find the corresponding bond mapping
-        let selected_mapping = {
-            let factory = &ctx.accounts.factory;
-            let fiat_bytes = args.fiat_currency.as_bytes();
-            let mut selected: Option<&BondCurrencyMapping> = None;
-            
-            for i in 0..factory.bond_mappings_count as usize {
-                let mapping = &factory.bond_mappings[i];
-                if mapping.active {
-                    let mapping_currency = &mapping.fiat_currency;
-                    let mapping_len = mapping_currency.iter().take_while(|&&b| b != 0).count();
-                    if mapping_len == fiat_bytes.len() {
-                        let mapping_prefix = &mapping_currency[..mapping_len];
-                        if mapping_prefix == fiat_bytes {
-                            selected = Some(mapping);
-                            break;
-                        }
-                    }
-                }
-            }
-            
-            selected.map(|m| m.clone()).ok_or(StablecoinError::NoBondMappingForCurrency)?
-        };
+        let selected_mapping = factory
+            .find_bond_mapping(args.fiat_currency.as_bytes())
+            .cloned()
+            .ok_or(StablecoinError::NoBondMappingForCurrency)?;
         
         // Verify bond mint
         require!(
@@ -136,6 +149,13 @@ impl InitSovereignCoin<'_> {
             StablecoinError::InvalidBondMint
         );
         
+        // Pins the mapping's bond mint until the coin is closed
+        let mapping = factory
+            .find_bond_mapping_mut(args.fiat_currency.as_bytes())
+            .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+        mapping.coin_count = mapping.coin_count.checked_add(1)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
         // Calculate bond account
         let (bond_account, _) = find_bond_pda(selected_mapping.bond_mint);
         sovereign_coin.bond_mint = selected_mapping.bond_mint;
@@ -144,16 +164,59 @@ impl InitSovereignCoin<'_> {
         
         // Calculate required reserve percentage
         sovereign_coin.required_reserve_percentage = calculate_required_reserve(
-            ctx.accounts.factory.min_fiat_reserve_percentage,
+            factory.min_fiat_reserve_percentage,
             selected_mapping.bond_rating,
-            ctx.accounts.factory.bond_reserve_multiplier
+            factory.bond_reserve_multiplier
         );
         
         // Initialize state
         sovereign_coin.decimals = 6;
+        sovereign_coin.metadata_standard = MetadataStandard::Metaplex; // Until a setup instruction picks one
+        sovereign_coin.transfer_hook_enabled = false;
+        sovereign_coin.interest_bearing = false;
+        sovereign_coin.permanent_delegate_enabled = false;
         sovereign_coin.total_supply = 0;
         sovereign_coin.fiat_amount = 0;
         sovereign_coin.bond_amount = 0;
+        sovereign_coin.protocol_owned_fiat = 0;
+        sovereign_coin.protocol_owned_bond = 0;
+        sovereign_coin.accrued_yield_fiat = 0;
+        sovereign_coin.accrued_yield_bond = 0;
+        sovereign_coin.bond_nav = 0;
+        sovereign_coin.reserve_nav = 0;
+        sovereign_coin.nav_updated_at = 0;
+        sovereign_coin.holder_rate_bps = 0;
+        sovereign_coin.last_yield_harvest_at = 0;
+        sovereign_coin.paused = 0;
+        
+        // Start at the widest limits the factory allows
+        sovereign_coin.max_supply = factory.coin_max_supply_cap;
+        sovereign_coin.mint_limit = RateLimit::new(factory.coin_mint_limit_cap);
+        sovereign_coin.redeem_limit = RateLimit::new(factory.coin_redeem_limit_cap);
+        sovereign_coin.fee_schedule = FeeSchedule::default();
+        sovereign_coin.permissionless = true; // Until the issuer turns on the allowlist
+        sovereign_coin.flash_mint_cap = 0;
+        sovereign_coin.flash_fee_bps = 0;
+        sovereign_coin.flash_outstanding = 0;
+        sovereign_coin.flash_fee_due = 0;
+        sovereign_coin.next_ticket_id = 0;
+        sovereign_coin.next_settle_ticket_id = 0;
+        sovereign_coin.queued_redemptions = 0;
+        sovereign_coin.finalized = false;
+        sovereign_coin.winding_down = false;
+        sovereign_coin.wind_down_started_at = 0;
+        sovereign_coin.wind_down_supply = 0;
+        sovereign_coin.wind_down_fiat = 0;
+        sovereign_coin.wind_down_bond = 0;
+        sovereign_coin.version = SOVEREIGN_COIN_VERSION;
+        sovereign_coin.reserved = [0u8; SOVEREIGN_COIN_RESERVED_BYTES];
+        
+        // The issuer starts with every coin role but guardian
+        ctx.accounts.coin_roles.init(
+            ctx.bumps.coin_roles,
+            sovereign_coin.key(),
+            ctx.accounts.authority.key(),
+        );
 
         // Emit event
         let clock = Clock::get()?;
@@ -171,6 +234,7 @@ impl InitSovereignCoin<'_> {
             required_reserve_percentage: sovereign_coin.required_reserve_percentage,
             fiat_amount: sovereign_coin.fiat_amount,
             bond_amount: sovereign_coin.bond_amount,
+            creation_fee,
             timestamp: clock.unix_timestamp,
         });
         
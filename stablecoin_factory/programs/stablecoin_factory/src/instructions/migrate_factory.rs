@@ -0,0 +1,76 @@
+use super::*;
+use anchor_lang::Discriminator;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateFactory<'info> {
+    #[account(
+        mut,
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Still in an old layout, so not deserialized; the discriminator
+    /// and length are checked by the migration
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub factory: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateFactory<'_> {
+    /// Brings a factory written by an older program version up to the
+    /// current layout. v1 and v2 factories were Borsh-encoded, so they are
+    /// decoded and rewritten in the zero-copy layout.
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let factory_info = ctx.accounts.factory.to_account_info();
+        let (legacy, from_version) = {
+            let data = factory_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Factory::DISCRIMINATOR,
+                StablecoinError::UnknownAccountLayout
+            );
+            require!(data.len() != Factory::LEN, StablecoinError::AccountAlreadyMigrated);
+            let from_version = match data.len() {
+                LegacyFactory::V1_LEN => 1,
+                LegacyFactory::V2_LEN => 2,
+                _ => return err!(StablecoinError::UnknownAccountLayout),
+            };
+            (LegacyFactory::deserialize(&mut &data[8..])?, from_version)
+        };
+        
+        resize_account(
+            &factory_info,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Factory::LEN,
+        )?;
+        let mut data = factory_info.try_borrow_mut_data()?;
+        data[8..].fill(0);
+        legacy.migrate(bytemuck::from_bytes_mut(&mut data[8..]));
+        drop(data);
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AccountMigratedEvent {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.factory.key(),
+            from_version,
+            to_version: FACTORY_VERSION,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+use anchor_lang::Discriminator;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateSovereignCoin<'info> {
+    #[account(
+        mut,
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Still in an old layout, so not deserialized; the discriminator
+    /// and length are checked by the migration
+    #[account(mut, owner = crate::ID)]
+    pub sovereign_coin: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateSovereignCoin<'_> {
+    /// Brings a coin written by an older program version up to the current
+    /// layout.
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        migrate_v1_layout(
+            &ctx.accounts.sovereign_coin.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &SovereignCoin::DISCRIMINATOR,
+            SovereignCoin::V1_LEN,
+            8 + SovereignCoin::INIT_SPACE,
+            SOVEREIGN_COIN_VERSION,
+        )?;
+        
+        // v1 coins predate the mapping's coin count
+        let sovereign_coin = SovereignCoin::try_deserialize(
+            &mut &ctx.accounts.sovereign_coin.try_borrow_data()?[..]
+        )?;
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        if let Some(coin_count) = factory
+            .find_registered_mapping_mut(&sovereign_coin.target_fiat_currency)
+            .and_then(|mapping| mapping.coin_count_mut(&sovereign_coin.bond_mint))
+        {
+            *coin_count = coin_count.checked_add(1)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+        }
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AccountMigratedEvent {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.sovereign_coin.key(),
+            from_version: 1,
+            to_version: SOVEREIGN_COIN_VERSION,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use super::*;
+use anchor_lang::solana_program::program_option::COption;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintSovereignCoin<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        mut,
+        constraint = mint.mint_authority == COption::Some(sovereign_coin.key()) @ StablecoinError::MintAuthorityNotDelegated,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::authority = user,
+        token::token_program = fiat_token_program,
+    )]
+    pub user_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub user_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = factory.load()?.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sovereign_coin.authority,
+        token::token_program = token_program,
+    )]
+    pub issuer_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Required while the factory routes part of the protocol fee to insurance
+    #[account(
+        mut,
+        seeds = [b"insurance_vault", sovereign_coin.fiat_currency_code()],
+        bump,
+        token::mint = fiat_token_mint,
+    )]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    // Present when the user is one of the coin's market makers
+    #[account(
+        seeds = [b"market_maker", sovereign_coin.key().as_ref(), user.key().as_ref()],
+        bump = market_maker.bump,
+    )]
+    pub market_maker: Option<Box<Account<'info, MarketMaker>>>,
+    
+    // Required while the coin is not permissionless
+    #[account(
+        seeds = [b"primary_market_allowlist", sovereign_coin.key().as_ref(), user.key().as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Option<Box<Account<'info, PrimaryMarketAllowlist>>>,
+    
+    // Present when a wallet or frontend routed the mint
+    #[account(
+        mut,
+        seeds = [b"referrer", sovereign_coin.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+        constraint = referrer.wallet != user.key() @ StablecoinError::SelfReferral,
+    )]
+    pub referrer: Option<Box<Account<'info, Referrer>>>,
+    
+    // Required with a referrer
+    #[account(
+        mut,
+        seeds = [b"referral_escrow", sovereign_coin.key().as_ref()],
+        bump,
+    )]
+    pub referral_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub fiat_token_program: Interface<'info, TokenInterface>,
+}
+
+impl MintSovereignCoin<'_> {
+    /// Mints `amount` coins against the same value of fiat deposited into the
+    /// reserve. Fees are taken in coins; a referrer's share of the protocol fee
+    /// is held in the referral escrow until claimed. The insurance share is paid
+    /// in fiat straight to the currency's vault, so those coins are never minted.
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let clock = Clock::get()?;
+        let accounts = &ctx.accounts;
+        let factory = accounts.factory.load()?;
+        let sovereign_coin = &accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&factory, PAUSE_MINT)?;
+        require!(!sovereign_coin.winding_down, StablecoinError::SovereignCoinWindingDown);
+        sovereign_coin.require_primary_market_access(accounts.allowlist_entry.is_some())?;
+        
+        let fees = calculate_fees(
+            &factory,
+            sovereign_coin,
+            FeeOperation::Mint,
+            amount,
+            accounts.market_maker.is_some(),
+        )?;
+        let referral_fee = match &accounts.referrer {
+            Some(_) => (fees.protocol_fee as u128 * factory.referral_share_bps as u128 / 10_000) as u64,
+            None => 0,
+        };
+        let insurance_fee = insurance_share(&factory, fees.protocol_fee - referral_fee);
+        drop(factory);
+        let referral_escrow = match &accounts.referral_escrow {
+            Some(escrow) => Some(escrow.to_account_info()),
+            None if referral_fee == 0 => None,
+            None => return err!(StablecoinError::ReferralEscrowRequired),
+        };
+        
+        // Each coin is worth one unit of its fiat, interest included
+        let to_fiat = |amount: u64| -> Result<u64> {
+            let value = scaled_amount(&accounts.mint.to_account_info(), amount, clock.unix_timestamp)?;
+            Ok(normalize_amount(value, sovereign_coin.decimals, accounts.fiat_token_mint.decimals)
+                .ok_or(StablecoinError::ArithmeticOverflow)?)
+        };
+        let fiat_deposited = to_fiat(amount)?;
+        require!(fiat_deposited > 0, StablecoinError::InvalidAmount);
+        let minted = amount - insurance_fee;
+        let fiat_backing = to_fiat(minted)?;
+        let fiat_insurance = fiat_deposited - fiat_backing;
+        require!(
+            accounts.insurance_vault.is_some() || fiat_insurance == 0,
+            StablecoinError::InsuranceVaultRequired
+        );
+        
+        for (destination, share) in [
+            (Some(accounts.fiat_reserve.to_account_info()), fiat_backing),
+            (accounts.insurance_vault.as_ref().map(|vault| vault.to_account_info()), fiat_insurance),
+        ] {
+            let Some(destination) = destination.filter(|_| share > 0) else {
+                continue;
+            };
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    accounts.fiat_token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.user_fiat_account.to_account_info(),
+                        mint: accounts.fiat_token_mint.to_account_info(),
+                        to: destination,
+                        authority: accounts.user.to_account_info(),
+                    },
+                ),
+                share,
+                accounts.fiat_token_mint.decimals,
+            )?;
+        }
+        
+        // Everything minted is backed, so the fees are minted too
+        let signer_seeds = sovereign_coin.signer_seeds();
+        for (destination, share) in [
+            (Some(accounts.user_coin_account.to_account_info()), fees.net_amount),
+            (Some(accounts.treasury_coin_account.to_account_info()), fees.protocol_fee - referral_fee - insurance_fee),
+            (referral_escrow, referral_fee),
+            (Some(accounts.issuer_coin_account.to_account_info()), fees.issuer_fee),
+        ] {
+            let Some(destination) = destination.filter(|_| share > 0) else {
+                continue;
+            };
+            token_interface::mint_to(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    token_interface::MintTo {
+                        mint: accounts.mint.to_account_info(),
+                        to: destination,
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    &[&signer_seeds],
+                ),
+                share,
+            )?;
+        }
+        
+        let supply_before = accounts.mint.supply;
+        let factory = ctx.accounts.factory.load()?;
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.record_mint(&factory, minted, supply_before, clock.unix_timestamp)?;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.saturating_add(minted);
+        sovereign_coin.fiat_amount = sovereign_coin.fiat_amount
+            .checked_add(fiat_backing)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let sovereign_coin_key = sovereign_coin.key();
+        
+        // Emit event
+        emit_cpi!(SovereignCoinMintedEvent {
+            user: ctx.accounts.user.key(),
+            sovereign_coin: sovereign_coin_key,
+            amount,
+            fiat_deposited,
+            insurance_fee: fiat_insurance,
+            net_amount: fees.net_amount,
+            referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.wallet),
+            timestamp: clock.unix_timestamp,
+        });
+        emit_cpi!(FeesChargedEvent {
+            payer: ctx.accounts.user.key(),
+            sovereign_coin: sovereign_coin_key,
+            operation: FeeOperation::Mint,
+            fees,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+            referrer.referral_count = referrer.referral_count
+                .checked_add(1)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+            referrer.total_volume = referrer.total_volume.saturating_add(amount);
+            referrer.accrued = referrer.accrued
+                .checked_add(referral_fee)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+            
+            emit_cpi!(ReferralAccruedEvent {
+                wallet: referrer.wallet,
+                sovereign_coin: sovereign_coin_key,
+                user: ctx.accounts.user.key(),
+                amount,
+                referral_fee,
+                referral_count: referrer.referral_count,
+                total_volume: referrer.total_volume,
+                accrued: referrer.accrued,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        Ok(())
+    }
+}
@@ -4,12 +4,124 @@ pub mod initialize_factory;
 pub mod initialize_stablecoin;
 pub mod setup_token_accounts;
 pub mod setup_mint;
+pub mod setup_token_2022_mint;
 pub mod finalize_setup;
 pub mod register_bond;
+pub mod init_manual_price;
+pub mod update_manual_price;
+pub mod refresh_reserve_nav;
+pub mod get_reserve_status;
+pub mod sync_reserves;
+pub mod harvest_yield;
+pub mod withdraw_accrued_yield;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod queue_factory_change;
+pub mod execute_factory_change;
+pub mod cancel_factory_change;
+pub mod set_coin_limits;
+pub mod set_coin_fees;
+pub mod add_market_maker;
+pub mod remove_market_maker;
+pub mod quote_fees;
+pub mod delegate_mint_authority;
+pub mod set_flash_mint_config;
+pub mod flash_mint;
+pub mod flash_repay;
+pub mod init_fx_rate;
+pub mod update_fx_rate;
+pub mod withdraw_fx_pool;
+pub mod swap_sovereign_coins;
+pub mod redeem_sovereign_coin;
+pub mod request_redemption;
+pub mod cancel_redemption;
+pub mod settle_redemption;
+pub mod rollover_bonds;
+pub mod rebalance_reserves;
+pub mod init_insurance_vault;
+pub mod cover_shortfall;
+pub mod register_referrer;
+pub mod mint_sovereign_coin;
+pub mod claim_referral_fees;
+pub mod register_issuer;
+pub mod approve_issuer;
+pub mod revoke_issuer;
+pub mod add_to_allowlist;
+pub mod remove_from_allowlist;
+pub mod set_permissionless;
+pub mod migrate_factory;
+pub mod migrate_sovereign_coin;
+pub mod freeze_holder;
+pub mod thaw_holder;
+pub mod seize_holder;
+pub mod pause_factory;
+pub mod unpause_factory;
+pub mod reset_circuit_breaker;
+pub mod pause_sovereign_coin;
+pub mod unpause_sovereign_coin;
+pub mod begin_wind_down;
+pub mod settle_redeem;
+pub mod close_sovereign_coin;
 
 pub use initialize_factory::*;
 pub use initialize_stablecoin::*;
 pub use setup_token_accounts::*;
 pub use setup_mint::*;
+pub use setup_token_2022_mint::*;
 pub use finalize_setup::*;
 pub use register_bond::*;
+pub use init_manual_price::*;
+pub use update_manual_price::*;
+pub use refresh_reserve_nav::*;
+pub use get_reserve_status::*;
+pub use sync_reserves::*;
+pub use harvest_yield::*;
+pub use withdraw_accrued_yield::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use queue_factory_change::*;
+pub use execute_factory_change::*;
+pub use cancel_factory_change::*;
+pub use set_coin_limits::*;
+pub use set_coin_fees::*;
+pub use add_market_maker::*;
+pub use remove_market_maker::*;
+pub use quote_fees::*;
+pub use delegate_mint_authority::*;
+pub use set_flash_mint_config::*;
+pub use flash_mint::*;
+pub use flash_repay::*;
+pub use init_fx_rate::*;
+pub use update_fx_rate::*;
+pub use withdraw_fx_pool::*;
+pub use swap_sovereign_coins::*;
+pub use redeem_sovereign_coin::*;
+pub use request_redemption::*;
+pub use cancel_redemption::*;
+pub use settle_redemption::*;
+pub use rollover_bonds::*;
+pub use rebalance_reserves::*;
+pub use init_insurance_vault::*;
+pub use cover_shortfall::*;
+pub use register_referrer::*;
+pub use mint_sovereign_coin::*;
+pub use claim_referral_fees::*;
+pub use register_issuer::*;
+pub use approve_issuer::*;
+pub use revoke_issuer::*;
+pub use add_to_allowlist::*;
+pub use remove_from_allowlist::*;
+pub use set_permissionless::*;
+pub use migrate_factory::*;
+pub use migrate_sovereign_coin::*;
+pub use freeze_holder::*;
+pub use thaw_holder::*;
+pub use seize_holder::*;
+pub use pause_factory::*;
+pub use unpause_factory::*;
+pub use reset_circuit_breaker::*;
+pub use pause_sovereign_coin::*;
+pub use unpause_sovereign_coin::*;
+pub use begin_wind_down::*;
+pub use settle_redeem::*;
+pub use close_sovereign_coin::*;
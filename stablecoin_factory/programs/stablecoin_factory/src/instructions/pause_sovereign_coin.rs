@@ -0,0 +1,63 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseSovereignCoin<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::Guardian, &guardian.key())
+            || coin_roles.has_role(Role::Guardian, &guardian.key()) @ StablecoinError::Unauthorized
+    )]
+    pub guardian: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+}
+
+impl PauseSovereignCoin<'_> {
+    pub fn handler(ctx: Context<Self>, flags: u8) -> Result<()> {
+        require!(flags != 0 && flags & !PAUSE_ALL == 0, StablecoinError::InvalidPauseFlags);
+        
+        // Only the compliance hook can stop transfers
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        require!(
+            flags & PAUSE_TRANSFER == 0 || sovereign_coin.transfer_hook_enabled,
+            StablecoinError::TransferPauseUnsupported
+        );
+        sovereign_coin.paused |= flags;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(SovereignCoinPauseUpdatedEvent {
+            signer: ctx.accounts.guardian.key(),
+            sovereign_coin: sovereign_coin.key(),
+            flags,
+            paused: sovereign_coin.paused,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueFactoryChange<'info> {
+    #[account(
+        mut,
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingChange::INIT_SPACE,
+        seeds = [b"pending_change", factory.key().as_ref(), &factory.load()?.next_change_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_change: Box<Account<'info, PendingChange>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl QueueFactoryChange<'_> {
+    pub fn handler(ctx: Context<Self>, change: FactoryChange) -> Result<()> {
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        change.validate(&factory)?;
+        
+        let clock = Clock::get()?;
+        let eta = clock.unix_timestamp
+            .checked_add(factory.timelock_delay)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        let pending_change = &mut ctx.accounts.pending_change;
+        pending_change.bump = ctx.bumps.pending_change;
+        pending_change.factory = ctx.accounts.factory.key();
+        pending_change.id = factory.next_change_id;
+        pending_change.change = change.clone();
+        pending_change.queued_by = ctx.accounts.authority.key();
+        pending_change.queued_at = clock.unix_timestamp;
+        pending_change.eta = eta;
+        
+        factory.next_change_id = factory.next_change_id
+            .checked_add(1)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Emit event
+        emit_cpi!(FactoryChangeQueuedEvent {
+            authority: ctx.accounts.authority.key(),
+            factory: ctx.accounts.factory.key(),
+            pending_change: pending_change.key(),
+            id: pending_change.id,
+            change,
+            eta,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
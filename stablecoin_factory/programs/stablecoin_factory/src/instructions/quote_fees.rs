@@ -0,0 +1,39 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct QuoteFees<'info> {
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    /// CHECK: Only used to derive the market maker account
+    pub wallet: UncheckedAccount<'info>,
+    
+    // Present when the wallet is one of the coin's market makers
+    #[account(
+        seeds = [b"market_maker", sovereign_coin.key().as_ref(), wallet.key().as_ref()],
+        bump = market_maker.bump,
+    )]
+    pub market_maker: Option<Box<Account<'info, MarketMaker>>>,
+}
+
+impl QuoteFees<'_> {
+    pub fn handler(ctx: Context<Self>, operation: FeeOperation, amount: u64) -> Result<FeeBreakdown> {
+        calculate_fees(
+            &*ctx.accounts.factory.load()?,
+            &ctx.accounts.sovereign_coin,
+            operation,
+            amount,
+            ctx.accounts.market_maker.is_some(),
+        )
+    }
+}
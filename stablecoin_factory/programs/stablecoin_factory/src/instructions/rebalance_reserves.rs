@@ -0,0 +1,276 @@
+use super::*;
+use stablebond_sdk::instructions::{
+    InstantBondRedemptionCpi, InstantBondRedemptionCpiAccounts, InstantBondRedemptionInstructionArgs,
+    PurchaseBondCpi, PurchaseBondCpiAccounts, PurchaseBondInstructionArgs,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RebalanceReserves<'info> {
+    pub keeper: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = bond_account,
+        has_one = bond_mint,
+        has_one = bond_holding,
+        has_one = fiat_reserve,
+        constraint = !sovereign_coin.winding_down @ StablecoinError::SovereignCoinWindingDown,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: Validated against the bond mapping's configured oracle when read
+    pub price_oracle: UncheckedAccount<'info>,
+    
+    /// CHECK: Stablebond Bond account, verified by the Stablebond program
+    #[account(mut)]
+    pub bond_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Current issuance of the bond, verified by the Stablebond program
+    #[account(mut)]
+    pub issuance: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub bond_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut)]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+    )]
+    pub keeper_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Stablebond accounts for buying
+    /// CHECK: Payment account of the current issuance, verified by the Stablebond program
+    #[account(mut)]
+    pub payment: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub payment_token_account: UncheckedAccount<'info>,
+    
+    // Stablebond accounts for selling
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub sell_liquidity: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub sell_liquidity_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub fee_collector_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    pub payment_feed: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    pub payment_base_price_feed: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    pub payment_quote_price_feed: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Stablebond program
+    #[account(address = stablebond_sdk::ID)]
+    pub stablebond_program: UncheckedAccount<'info>,
+    
+    /// CHECK: Associated token program, verified by the Stablebond program
+    pub associated_token_program: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RebalanceReserves<'_> {
+    /// Crank: trades between the fiat reserve and bonds when the fiat buffer
+    /// drifts outside the factory's band around the coin's requirement.
+    pub fn handler(mut ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let accounts = &mut ctx.accounts;
+        let factory = accounts.factory.load()?;
+        accounts.sovereign_coin.require_not_paused(&factory, PAUSE_YIELD)?;
+        
+        let band_bps = factory.rebalance_band_bps;
+        let keeper_reward_bps = factory.keeper_reward_bps;
+        require!(band_bps > 0, StablecoinError::RebalancingDisabled);
+        
+        let price = load_coin_bond_price(
+            &factory,
+            &accounts.sovereign_coin,
+            &accounts.price_oracle.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        drop(factory);
+        
+        // Target and band, both in fiat base units
+        let fiat_decimals = accounts.fiat_token_mint.decimals;
+        let bond_decimals = accounts.bond_mint.decimals;
+        let scaled_supply = scaled_amount(
+            &accounts.mint.to_account_info(),
+            accounts.mint.supply,
+            clock.unix_timestamp,
+        )?;
+        let supply_value = normalize_amount(scaled_supply, accounts.mint.decimals, fiat_decimals)
+            .ok_or(StablecoinError::ArithmeticOverflow)? as u128;
+        let target_fiat = (supply_value * accounts.sovereign_coin.required_reserve_percentage as u128 / 100) as u64;
+        let band = (supply_value * band_bps as u128 / 10_000) as u64;
+        
+        let fiat_before = accounts.sovereign_coin.backing_fiat(accounts.fiat_reserve.amount);
+        let direction = if fiat_before > target_fiat.saturating_add(band) {
+            RebalanceDirection::BuyBonds
+        } else if fiat_before.saturating_add(band) < target_fiat {
+            RebalanceDirection::SellBonds
+        } else {
+            return err!(StablecoinError::ReservesWithinBand);
+        };
+        
+        let signer_seeds = accounts.sovereign_coin.signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        let coin_info = accounts.sovereign_coin.to_account_info();
+        let stablebond_program = accounts.stablebond_program.to_account_info();
+        let payment_quote_price_feed = accounts.payment_quote_price_feed.as_ref().map(|feed| feed.to_account_info());
+        let reserve_fiat = accounts.fiat_reserve.amount;
+        let reserve_bond = accounts.bond_holding.amount;
+        
+        match direction {
+            RebalanceDirection::BuyBonds => {
+                PurchaseBondCpi::new(
+                    &stablebond_program,
+                    PurchaseBondCpiAccounts {
+                        user_wallet: &coin_info,
+                        bond_account: &accounts.bond_account.to_account_info(),
+                        issuance_account: &accounts.issuance.to_account_info(),
+                        mint_account: &accounts.bond_mint.to_account_info(),
+                        payment_account: &accounts.payment.to_account_info(),
+                        payment_token_account: &accounts.payment_token_account.to_account_info(),
+                        user_token_account: &accounts.bond_holding.to_account_info(),
+                        user_payment_token_account: &accounts.fiat_reserve.to_account_info(),
+                        payment_mint_account: &accounts.fiat_token_mint.to_account_info(),
+                        payment_feed_account: &accounts.payment_feed.to_account_info(),
+                        payment_base_price_feed_account: &accounts.payment_base_price_feed.to_account_info(),
+                        token2022_program: &accounts.token_2022_program.to_account_info(),
+                        associated_token_program: &accounts.associated_token_program.to_account_info(),
+                        token_program: &accounts.token_program.to_account_info(),
+                        system_program: &accounts.system_program.to_account_info(),
+                        payment_quote_price_feed_account: payment_quote_price_feed.as_ref(),
+                    },
+                    PurchaseBondInstructionArgs { amount: fiat_before - target_fiat },
+                )
+                .invoke_signed(signer)?;
+            }
+            RebalanceDirection::SellBonds => {
+                // Sell what the oracle says covers the shortfall, up to the backing bonds
+                let bond_to_sell = convert_at_price(target_fiat - fiat_before, fiat_decimals, bond_decimals, &price, true)
+                    .ok_or(StablecoinError::ArithmeticOverflow)?
+                    .min(accounts.sovereign_coin.backing_bond(reserve_bond));
+                require!(bond_to_sell > 0, StablecoinError::NoBondsToSell);
+                
+                InstantBondRedemptionCpi::new(
+                    &stablebond_program,
+                    InstantBondRedemptionCpiAccounts {
+                        user_wallet: &coin_info,
+                        user_bond_token_account: &accounts.bond_holding.to_account_info(),
+                        user_payment_token_account: &accounts.fiat_reserve.to_account_info(),
+                        bond_account: &accounts.bond_account.to_account_info(),
+                        mint_account: &accounts.bond_mint.to_account_info(),
+                        issuance_account: &accounts.issuance.to_account_info(),
+                        payment_mint_account: &accounts.fiat_token_mint.to_account_info(),
+                        payment_feed_account: &accounts.payment_feed.to_account_info(),
+                        sell_liquidity_account: &accounts.sell_liquidity.to_account_info(),
+                        sell_liquidity_token_account: &accounts.sell_liquidity_token_account.to_account_info(),
+                        fee_collector_wallet_token_account: &accounts.fee_collector_token_account.to_account_info(),
+                        payment_base_price_feed_account: &accounts.payment_base_price_feed.to_account_info(),
+                        token_program: &accounts.token_program.to_account_info(),
+                        token2022_program: &accounts.token_2022_program.to_account_info(),
+                        associated_token_program: &accounts.associated_token_program.to_account_info(),
+                        system_program: &accounts.system_program.to_account_info(),
+                        payment_quote_price_feed_account: payment_quote_price_feed.as_ref(),
+                    },
+                    InstantBondRedemptionInstructionArgs { amount: bond_to_sell },
+                )
+                .invoke_signed(signer)?;
+            }
+        }
+        
+        accounts.fiat_reserve.reload()?;
+        accounts.bond_holding.reload()?;
+        let fiat_moved = accounts.fiat_reserve.amount.abs_diff(reserve_fiat);
+        let bond_moved = accounts.bond_holding.amount.abs_diff(reserve_bond);
+        
+        // Keeper reward comes out of yield set aside in the fiat reserve, never backing
+        let keeper_reward = ((fiat_moved as u128 * keeper_reward_bps as u128 / 10_000) as u64)
+            .min(accounts.sovereign_coin.accrued_yield_fiat);
+        if keeper_reward > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.fiat_reserve.to_account_info(),
+                        mint: accounts.fiat_token_mint.to_account_info(),
+                        to: accounts.keeper_fiat_account.to_account_info(),
+                        authority: coin_info,
+                    },
+                    signer,
+                ),
+                keeper_reward,
+                fiat_decimals,
+            )?;
+        }
+        
+        let sovereign_coin = &mut accounts.sovereign_coin;
+        match direction {
+            RebalanceDirection::BuyBonds => {
+                sovereign_coin.fiat_amount = sovereign_coin.fiat_amount.saturating_sub(fiat_moved);
+                sovereign_coin.bond_amount = sovereign_coin.bond_amount
+                    .checked_add(bond_moved)
+                    .ok_or(StablecoinError::ArithmeticOverflow)?;
+            }
+            RebalanceDirection::SellBonds => {
+                sovereign_coin.fiat_amount = sovereign_coin.fiat_amount
+                    .checked_add(fiat_moved)
+                    .ok_or(StablecoinError::ArithmeticOverflow)?;
+                sovereign_coin.bond_amount = sovereign_coin.bond_amount.saturating_sub(bond_moved);
+            }
+        }
+        sovereign_coin.accrued_yield_fiat -= keeper_reward;
+        
+        // Emit event
+        let keeper = accounts.keeper.key();
+        let sovereign_coin = accounts.sovereign_coin.key();
+        emit_cpi!(ReservesRebalancedEvent {
+            keeper,
+            sovereign_coin,
+            direction,
+            target_fiat,
+            fiat_before,
+            fiat_moved,
+            bond_moved,
+            keeper_reward,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
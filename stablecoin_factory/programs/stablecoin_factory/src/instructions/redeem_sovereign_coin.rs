@@ -0,0 +1,284 @@
+use super::*;
+use spl_token_2022::onchain::invoke_transfer_checked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RedeemSovereignCoin<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::token_program = fiat_token_program,
+    )]
+    pub fiat_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::authority = factory.load()?.treasury,
+    )]
+    pub treasury_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::authority = sovereign_coin.authority,
+    )]
+    pub issuer_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Required while the factory routes part of the protocol fee to insurance
+    #[account(
+        mut,
+        seeds = [b"insurance_vault", sovereign_coin.fiat_currency_code()],
+        bump,
+        token::mint = fiat_token_mint,
+    )]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    // The escrow and ticket are only used when the redemption has to queue.
+    // A ticket passed for a redemption that settles straight away is closed again
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [b"redemption_escrow", sovereign_coin.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = sovereign_coin,
+        token::token_program = token_program,
+    )]
+    pub redemption_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + RedemptionTicket::INIT_SPACE,
+        seeds = [b"redemption_ticket", sovereign_coin.key().as_ref(), &sovereign_coin.next_ticket_id.to_le_bytes()],
+        bump
+    )]
+    pub ticket: Option<Box<Account<'info, RedemptionTicket>>>,
+    
+    // Present when the holder is one of the coin's market makers
+    #[account(
+        seeds = [b"market_maker", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump = market_maker.bump,
+    )]
+    pub market_maker: Option<Box<Account<'info, MarketMaker>>>,
+    
+    // Required while the coin is not permissionless
+    #[account(
+        seeds = [b"primary_market_allowlist", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Option<Box<Account<'info, PrimaryMarketAllowlist>>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub fiat_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemSovereignCoin<'info> {
+    /// Burns `amount` coins for the same value of fiat, less fees, when the
+    /// queue is empty and the fiat reserve can pay at par. Otherwise the coins
+    /// are escrowed behind a new ticket, as request_redemption does. Accounts
+    /// required by the compliance transfer hook, if any, go in remaining_accounts.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let clock = Clock::get()?;
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&factory, PAUSE_REDEEM)?;
+        require!(!sovereign_coin.winding_down, StablecoinError::SovereignCoinWindingDown);
+        sovereign_coin.require_primary_market_access(ctx.accounts.allowlist_entry.is_some())?;
+        
+        let fees = calculate_fees(
+            &factory,
+            sovereign_coin,
+            FeeOperation::Redeem,
+            amount,
+            ctx.accounts.market_maker.is_some(),
+        )?;
+        let fiat_decimals = ctx.accounts.fiat_token_mint.decimals;
+        let payout = RedemptionPayout::new(
+            &factory,
+            &fees,
+            &ctx.accounts.mint.to_account_info(),
+            sovereign_coin.decimals,
+            fiat_decimals,
+            clock.unix_timestamp,
+        )?;
+        
+        // Queued tickets were there first
+        let settles_now = sovereign_coin.queued_redemptions == 0
+            && sovereign_coin.backing_fiat(ctx.accounts.fiat_reserve.amount) >= payout.total();
+        if !settles_now {
+            drop(factory);
+            return Self::queue(ctx, amount, fees, clock.unix_timestamp);
+        }
+        
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.holder_coin_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        // Pay the holder and the fees out of the fiat reserve, the insurance
+        // share of the protocol fee to the currency's vault
+        let signer_seeds = sovereign_coin.signer_seeds();
+        for (destination, share) in payout.transfers(
+            ctx.accounts.fiat_destination.to_account_info(),
+            ctx.accounts.treasury_fiat_account.to_account_info(),
+            ctx.accounts.insurance_vault.as_ref().map(|vault| vault.to_account_info()),
+            ctx.accounts.issuer_fiat_account.to_account_info(),
+        )? {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.fiat_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.fiat_reserve.to_account_info(),
+                        mint: ctx.accounts.fiat_token_mint.to_account_info(),
+                        to: destination,
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    &[&signer_seeds],
+                ),
+                share,
+                fiat_decimals,
+            )?;
+        }
+        
+        if let Some(ticket) = &ctx.accounts.ticket {
+            ticket.close(ctx.accounts.holder.to_account_info())?;
+        }
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.record_redeem(&factory, amount, clock.unix_timestamp)?;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.saturating_sub(amount);
+        sovereign_coin.fiat_amount = sovereign_coin.fiat_amount.saturating_sub(payout.total());
+        
+        let tripped = factory.record_outflow(&sovereign_coin.target_fiat_currency, amount, clock.unix_timestamp);
+        
+        // Emit event
+        emit_cpi!(SovereignCoinRedeemedEvent {
+            holder: ctx.accounts.holder.key(),
+            sovereign_coin: sovereign_coin.key(),
+            amount,
+            fiat_paid: payout.fiat_paid,
+            insurance_fee: payout.insurance_fee,
+            timestamp: clock.unix_timestamp,
+        });
+        emit_cpi!(FeesChargedEvent {
+            payer: ctx.accounts.holder.key(),
+            sovereign_coin: sovereign_coin.key(),
+            operation: FeeOperation::Redeem,
+            fees,
+            timestamp: clock.unix_timestamp,
+        });
+        if tripped {
+            emit_cpi!(CircuitBreakerTrippedEvent {
+                factory: ctx.accounts.factory.key(),
+                trip: factory.breaker_trip(),
+                flags: factory.breaker_flags,
+                paused: factory.paused,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Escrows the coins behind a ticket at the back of the queue, with the
+    /// fees fixed now.
+    fn queue(ctx: Context<'_, '_, 'info, 'info, Self>, amount: u64, fees: FeeBreakdown, now: i64) -> Result<()> {
+        let (Some(redemption_escrow), Some(ticket), Some(ticket_bump)) = (
+            ctx.accounts.redemption_escrow.as_ref(),
+            ctx.accounts.ticket.as_mut(),
+            ctx.bumps.ticket,
+        ) else {
+            return err!(StablecoinError::RedemptionTicketRequired);
+        };
+        
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.holder_coin_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            redemption_escrow.to_account_info(),
+            ctx.accounts.holder.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        ticket.bump = ticket_bump;
+        ticket.sovereign_coin = sovereign_coin.key();
+        ticket.id = sovereign_coin.next_ticket_id;
+        ticket.owner = ctx.accounts.holder.key();
+        ticket.fiat_destination = ctx.accounts.fiat_destination.key();
+        ticket.amount = amount;
+        ticket.fees = fees;
+        ticket.status = TicketStatus::Queued;
+        ticket.queued_at = now;
+        
+        sovereign_coin.next_ticket_id += 1;
+        sovereign_coin.queued_redemptions = sovereign_coin.queued_redemptions
+            .checked_add(amount)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Emit event
+        emit_cpi!(RedemptionQueuedEvent {
+            holder: ticket.owner,
+            sovereign_coin: ticket.sovereign_coin,
+            ticket: ticket.key(),
+            id: ticket.id,
+            amount,
+            fees,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefreshReserveNav<'info> {
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    /// CHECK: Validated against the bond mapping's configured oracle when read
+    pub price_oracle: UncheckedAccount<'info>,
+    
+    #[account(address = sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint)]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+impl RefreshReserveNav<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        
+        let price = load_coin_bond_price(
+            &*ctx.accounts.factory.load()?,
+            sovereign_coin,
+            &ctx.accounts.price_oracle.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        
+        // Value what the token accounts actually hold, not the cached amounts
+        let fiat_amount = sovereign_coin.backing_fiat(ctx.accounts.fiat_reserve.amount);
+        let bond_amount = sovereign_coin.backing_bond(ctx.accounts.bond_holding.amount);
+        
+        let bond_nav = calculate_bond_value(
+            bond_amount,
+            ctx.accounts.bond_token_mint.decimals,
+            ctx.accounts.fiat_token_mint.decimals,
+            &price,
+        ).ok_or(StablecoinError::ArithmeticOverflow)?;
+        let reserve_nav = calculate_reserve_nav(fiat_amount, bond_nav)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        sovereign_coin.bond_nav = bond_nav;
+        sovereign_coin.reserve_nav = reserve_nav;
+        sovereign_coin.nav_updated_at = clock.unix_timestamp;
+        
+        // Every fresh price feeds the factory circuit breaker
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        let tripped = factory.observe_bond_price(
+            sovereign_coin.fiat_currency_code(),
+            price.price,
+            price.expo,
+            clock.unix_timestamp,
+        );
+        
+        // Emit event
+        emit_cpi!(ReserveNavUpdatedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            bond_price: price.price,
+            bond_price_expo: price.expo,
+            fiat_amount,
+            bond_amount,
+            bond_nav,
+            reserve_nav,
+            timestamp: clock.unix_timestamp,
+        });
+        if tripped {
+            emit_cpi!(CircuitBreakerTrippedEvent {
+                factory: ctx.accounts.factory.key(),
+                trip: factory.breaker_trip(),
+                flags: factory.breaker_flags,
+                paused: factory.paused,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        Ok(())
+    }
+}
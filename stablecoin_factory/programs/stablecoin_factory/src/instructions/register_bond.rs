@@ -4,12 +4,18 @@ use super::*;
 #[derive(Accounts)]
 pub struct RegisterBondMapping<'info> {
     #[account(
-        constraint = authority.key() == factory.authority @ StablecoinError::Unauthorized
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
     )]
     pub authority: Signer<'info>,
     
     #[account(mut)]
-    pub factory: Box<Account<'info, Factory>>,
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
 }
 
 impl RegisterBondMapping<'_> {
@@ -21,11 +27,11 @@ impl RegisterBondMapping<'_> {
     ) -> Result<()> {
         // Validate bond rating
         require!(
-            bond_rating >= 1 && bond_rating <= 10, 
+            (1..=10).contains(&bond_rating), 
             StablecoinError::InvalidBondRating
         );
         
-        let factory = &mut ctx.accounts.factory;
+        let mut factory = ctx.accounts.factory.load_mut()?;
         
         // Ensure we're not exceeding max mappings
         require!(
@@ -36,17 +42,33 @@ impl RegisterBondMapping<'_> {
         // Check if this currency is already mapped
         let fiat_bytes = fiat_currency.as_bytes();
         require!(fiat_bytes.len() <= 8, StablecoinError::FiatCurrencyTooLong);
+        let mut padded_currency = [0u8; 8];
+        padded_currency[..fiat_bytes.len()].copy_from_slice(fiat_bytes);
+        
+        // Existing mappings only change through the timelock
+        require!(
+            factory.find_registered_mapping(&padded_currency).is_none(),
+            StablecoinError::BondMappingExists
+        );
         
         // Add the new mapping
         let index = factory.bond_mappings_count as usize;
         let mapping = &mut factory.bond_mappings[index];
-        mapping.active = true;
-        
-        mapping.fiat_currency = [0u8; 8];
-        mapping.fiat_currency[..fiat_bytes.len()].copy_from_slice(fiat_bytes);
+        mapping.active = 1;
+        mapping.fiat_currency = padded_currency;
         
         mapping.bond_mint = bond_mint;
         mapping.bond_rating = bond_rating;  // Store the bond rating
+        mapping.coin_count = 0;
+        mapping.outflow_window_start = 0;
+        mapping.outflow_used = 0;
+        
+        // Price feed is configured separately through a BondOracle change
+        mapping.oracle_source = OracleSource::None as u8;
+        mapping.price_oracle = Pubkey::default();
+        mapping.max_price_age = 0;
+        mapping.max_confidence_bps = 0;
+        mapping.reset_reference_price();
         
         // Increment counter
         factory.bond_mappings_count += 1;
@@ -0,0 +1,51 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterIssuer<'info> {
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = issuer,
+        space = 8 + IssuerProfile::INIT_SPACE,
+        seeds = [b"issuer_profile", issuer.key().as_ref()],
+        bump
+    )]
+    pub issuer_profile: Box<Account<'info, IssuerProfile>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl RegisterIssuer<'_> {
+    pub fn handler(ctx: Context<Self>, jurisdiction: String) -> Result<()> {
+        require!(
+            !jurisdiction.is_empty() && jurisdiction.len() <= 8,
+            StablecoinError::InvalidJurisdiction
+        );
+        
+        let clock = Clock::get()?;
+        let issuer_profile = &mut ctx.accounts.issuer_profile;
+        issuer_profile.bump = ctx.bumps.issuer_profile;
+        issuer_profile.issuer = ctx.accounts.issuer.key();
+        issuer_profile.kyc_status = KycStatus::Pending;
+        issuer_profile.jurisdiction = [0u8; 8];
+        issuer_profile.jurisdiction[..jurisdiction.len()].copy_from_slice(jurisdiction.as_bytes());
+        issuer_profile.coin_quota = 0;
+        issuer_profile.coins_created = 0;
+        issuer_profile.revoked = false;
+        issuer_profile.registered_at = clock.unix_timestamp;
+        issuer_profile.approved_at = 0;
+        
+        // Emit event
+        emit_cpi!(IssuerRegisteredEvent {
+            issuer: issuer_profile.issuer,
+            issuer_profile: issuer_profile.key(),
+            jurisdiction,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
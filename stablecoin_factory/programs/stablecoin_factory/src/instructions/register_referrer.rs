@@ -0,0 +1,67 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [b"referrer", sovereign_coin.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+    
+    // Shared by every referrer of the coin
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        seeds = [b"referral_escrow", sovereign_coin.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = sovereign_coin,
+        token::token_program = token_program,
+    )]
+    pub referral_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RegisterReferrer<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        
+        let referrer = &mut ctx.accounts.referrer;
+        referrer.bump = ctx.bumps.referrer;
+        referrer.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        referrer.wallet = ctx.accounts.wallet.key();
+        referrer.referral_count = 0;
+        referrer.total_volume = 0;
+        referrer.accrued = 0;
+        referrer.total_claimed = 0;
+        referrer.registered_at = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(ReferrerRegisteredEvent {
+            wallet: referrer.wallet,
+            sovereign_coin: referrer.sovereign_coin,
+            referrer: referrer.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
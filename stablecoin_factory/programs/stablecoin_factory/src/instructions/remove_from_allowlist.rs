@@ -0,0 +1,51 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveFromAllowlist<'info> {
+    /// CHECK: Receives the allowlist entry's rent
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"primary_market_allowlist", sovereign_coin.key().as_ref(), wallet.as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Box<Account<'info, PrimaryMarketAllowlist>>,
+}
+
+impl RemoveFromAllowlist<'_> {
+    pub fn handler(ctx: Context<Self>, wallet: Pubkey) -> Result<()> {
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AllowlistUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            wallet,
+            added: false,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use super::*;
+use spl_token_2022::onchain::invoke_transfer_checked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Holds the coins of every queued ticket for this coin
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [b"redemption_escrow", sovereign_coin.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = sovereign_coin,
+        token::token_program = token_program,
+    )]
+    pub redemption_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(constraint = fiat_destination.mint == fiat_reserve.mint @ StablecoinError::InvalidSovereignCoinAccount)]
+    pub fiat_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + RedemptionTicket::INIT_SPACE,
+        seeds = [b"redemption_ticket", sovereign_coin.key().as_ref(), &sovereign_coin.next_ticket_id.to_le_bytes()],
+        bump
+    )]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+    
+    // Present when the holder is one of the coin's market makers
+    #[account(
+        seeds = [b"market_maker", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump = market_maker.bump,
+    )]
+    pub market_maker: Option<Box<Account<'info, MarketMaker>>>,
+    
+    // Required while the coin is not permissionless
+    #[account(
+        seeds = [b"primary_market_allowlist", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Option<Box<Account<'info, PrimaryMarketAllowlist>>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestRedemption<'info> {
+    /// Moves `amount` coins into escrow behind a new ticket at the back of the
+    /// queue. Fees are fixed now. Accounts required by the compliance transfer
+    /// hook, if any, go in remaining_accounts.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let factory = ctx.accounts.factory.load()?;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&factory, PAUSE_REDEEM)?;
+        require!(!sovereign_coin.winding_down, StablecoinError::SovereignCoinWindingDown);
+        sovereign_coin.require_primary_market_access(ctx.accounts.allowlist_entry.is_some())?;
+        
+        let fees = calculate_fees(
+            &factory,
+            sovereign_coin,
+            FeeOperation::Redeem,
+            amount,
+            ctx.accounts.market_maker.is_some(),
+        )?;
+        
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.holder_coin_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.redemption_escrow.to_account_info(),
+            ctx.accounts.holder.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        
+        let clock = Clock::get()?;
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        let ticket = &mut ctx.accounts.ticket;
+        ticket.bump = ctx.bumps.ticket;
+        ticket.sovereign_coin = sovereign_coin.key();
+        ticket.id = sovereign_coin.next_ticket_id;
+        ticket.owner = ctx.accounts.holder.key();
+        ticket.fiat_destination = ctx.accounts.fiat_destination.key();
+        ticket.amount = amount;
+        ticket.fees = fees;
+        ticket.status = TicketStatus::Queued;
+        ticket.queued_at = clock.unix_timestamp;
+        
+        sovereign_coin.next_ticket_id += 1;
+        sovereign_coin.queued_redemptions = sovereign_coin.queued_redemptions
+            .checked_add(amount)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Emit event
+        emit_cpi!(RedemptionQueuedEvent {
+            holder: ticket.owner,
+            sovereign_coin: ticket.sovereign_coin,
+            ticket: ticket.key(),
+            id: ticket.id,
+            amount,
+            fees,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::Guardian, &guardian.key()) @ StablecoinError::Unauthorized
+    )]
+    pub guardian: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+}
+
+impl ResetCircuitBreaker<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        require!(factory.is_breaker_tripped(), StablecoinError::BreakerNotTripped);
+        
+        let trip = factory.breaker_trip();
+        factory.reset_breaker();
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(CircuitBreakerResetEvent {
+            guardian: ctx.accounts.guardian.key(),
+            factory: ctx.accounts.factory.key(),
+            trip,
+            paused: factory.paused,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeIssuer<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"issuer_profile", issuer_profile.issuer.as_ref()],
+        bump = issuer_profile.bump,
+    )]
+    pub issuer_profile: Box<Account<'info, IssuerProfile>>,
+}
+
+impl RevokeIssuer<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let issuer_profile = &mut ctx.accounts.issuer_profile;
+        issuer_profile.revoked = true;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(IssuerRevokedEvent {
+            authority: ctx.accounts.authority.key(),
+            issuer: issuer_profile.issuer,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
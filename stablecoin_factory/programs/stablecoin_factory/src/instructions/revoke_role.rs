@@ -0,0 +1,40 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        constraint = roles.has_role(Role::IssuerAdmin, &admin.key()) @ StablecoinError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"roles", roles.scope.as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+}
+
+impl RevokeRole<'_> {
+    pub fn handler(ctx: Context<Self>, role: Role) -> Result<()> {
+        // Without an admin nobody could ever grant roles again
+        require!(role != Role::IssuerAdmin, StablecoinError::CannotRevokeIssuerAdmin);
+        
+        let roles = &mut ctx.accounts.roles;
+        let old_holder = roles.get(role);
+        roles.set(role, Pubkey::default());
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(RoleRevokedEvent {
+            admin: ctx.accounts.admin.key(),
+            scope: roles.scope,
+            role,
+            old_holder,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
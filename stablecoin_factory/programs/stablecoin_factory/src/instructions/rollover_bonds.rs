@@ -0,0 +1,289 @@
+use super::*;
+use stablebond_sdk::instructions::{
+    InstantBondRedemptionCpi, InstantBondRedemptionCpiAccounts, InstantBondRedemptionInstructionArgs,
+    PurchaseBondCpi, PurchaseBondCpiAccounts, PurchaseBondInstructionArgs,
+};
+use stablebond_sdk::types::IssuanceStatus;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RolloverBonds<'info> {
+    // Anyone may crank; pays for the new bond holding account
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = bond_account,
+        has_one = bond_mint,
+        has_one = bond_holding,
+        has_one = fiat_reserve,
+        constraint = !sovereign_coin.winding_down @ StablecoinError::SovereignCoinWindingDown,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    // Matured position
+    /// CHECK: Stablebond Bond account, deserialized in the handler
+    #[account(mut)]
+    pub bond_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Current issuance of the matured bond, checked in the handler
+    #[account(mut)]
+    pub issuance: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub bond_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut)]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    // Stablebond redemption liquidity
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub sell_liquidity: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub sell_liquidity_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub fee_collector_token_account: UncheckedAccount<'info>,
+    
+    // New issue
+    /// CHECK: Stablebond Bond account, deserialized in the handler
+    #[account(mut)]
+    pub new_bond_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Current issuance of the new bond, checked in the handler
+    #[account(mut)]
+    pub new_issuance: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub new_bond_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = cranker,
+        token::mint = new_bond_mint,
+        token::authority = sovereign_coin,
+        token::token_program = token_2022_program,
+    )]
+    pub new_bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: Payment account of the new issuance, verified by the Stablebond program
+    #[account(mut)]
+    pub payment: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    #[account(mut)]
+    pub payment_token_account: UncheckedAccount<'info>,
+    
+    // Both bonds share a payment feed, as they pay out in the same currency
+    /// CHECK: Verified by the Stablebond program
+    pub payment_feed: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    pub payment_base_price_feed: UncheckedAccount<'info>,
+    
+    /// CHECK: Verified by the Stablebond program
+    pub payment_quote_price_feed: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Stablebond program
+    #[account(address = stablebond_sdk::ID)]
+    pub stablebond_program: UncheckedAccount<'info>,
+    
+    /// CHECK: Associated token program, verified by the Stablebond program
+    pub associated_token_program: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RolloverBonds<'_> {
+    /// Crank: once the coin's bond issue has matured and the mapping has moved
+    /// to a new issue, redeems the whole position at par and buys the new
+    /// issue with the proceeds.
+    pub fn handler(mut ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let accounts = &mut ctx.accounts;
+        
+        let factory = accounts.factory.load()?;
+        accounts.sovereign_coin.require_not_paused(&factory, PAUSE_YIELD)?;
+        
+        // Only off the mint the mapping moved away from, into the one it moved
+        // to; both are set through the timelock
+        let mapping = factory
+            .find_registered_mapping(&accounts.sovereign_coin.target_fiat_currency)
+            .copied()
+            .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+        drop(factory);
+        let new_bond_mint = accounts.new_bond_mint.key();
+        require!(
+            accounts.sovereign_coin.bond_mint == mapping.previous_bond_mint
+                && new_bond_mint == mapping.bond_mint,
+            StablecoinError::InvalidBondMint
+        );
+        
+        // The held issue must have matured
+        let bond_info = accounts.bond_account.to_account_info();
+        let bond = load_bond(&bond_info)?;
+        let issuance = load_current_issuance(&bond_info, &bond, &accounts.issuance.to_account_info())?;
+        require!(is_matured(&issuance, clock.unix_timestamp), StablecoinError::BondNotMatured);
+        
+        // The new issue must be a live bond paying out in the same currency
+        let new_bond_info = accounts.new_bond_account.to_account_info();
+        require_keys_eq!(
+            new_bond_info.key(),
+            find_bond_pda(new_bond_mint).0,
+            StablecoinError::InvalidStablebondAccount
+        );
+        let new_bond = load_bond(&new_bond_info)?;
+        require!(new_bond.payment_feed_type == bond.payment_feed_type, StablecoinError::BondCurrencyMismatch);
+        let new_issuance = load_current_issuance(&new_bond_info, &new_bond, &accounts.new_issuance.to_account_info())?;
+        require!(
+            new_issuance.status == IssuanceStatus::Started && !is_matured(&new_issuance, clock.unix_timestamp),
+            StablecoinError::BondNotPurchasable
+        );
+        
+        let bond_redeemed = accounts.bond_holding.amount;
+        require!(bond_redeemed > 0, StablecoinError::NothingToRollOver);
+        
+        let signer_seeds = accounts.sovereign_coin.signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        let coin_info = accounts.sovereign_coin.to_account_info();
+        let stablebond_program = accounts.stablebond_program.to_account_info();
+        let token_program = accounts.token_program.to_account_info();
+        let token_2022_program = accounts.token_2022_program.to_account_info();
+        let associated_token_program = accounts.associated_token_program.to_account_info();
+        let system_program = accounts.system_program.to_account_info();
+        let fiat_reserve_info = accounts.fiat_reserve.to_account_info();
+        let fiat_mint_info = accounts.fiat_token_mint.to_account_info();
+        let payment_feed = accounts.payment_feed.to_account_info();
+        let payment_base_price_feed = accounts.payment_base_price_feed.to_account_info();
+        let payment_quote_price_feed = accounts.payment_quote_price_feed.as_ref().map(|feed| feed.to_account_info());
+        
+        // Redeem the matured position into the fiat reserve
+        let fiat_before = accounts.fiat_reserve.amount;
+        InstantBondRedemptionCpi::new(
+            &stablebond_program,
+            InstantBondRedemptionCpiAccounts {
+                user_wallet: &coin_info,
+                user_bond_token_account: &accounts.bond_holding.to_account_info(),
+                user_payment_token_account: &fiat_reserve_info,
+                bond_account: &bond_info,
+                mint_account: &accounts.bond_mint.to_account_info(),
+                issuance_account: &accounts.issuance.to_account_info(),
+                payment_mint_account: &fiat_mint_info,
+                payment_feed_account: &payment_feed,
+                sell_liquidity_account: &accounts.sell_liquidity.to_account_info(),
+                sell_liquidity_token_account: &accounts.sell_liquidity_token_account.to_account_info(),
+                fee_collector_wallet_token_account: &accounts.fee_collector_token_account.to_account_info(),
+                payment_base_price_feed_account: &payment_base_price_feed,
+                token_program: &token_program,
+                token2022_program: &token_2022_program,
+                associated_token_program: &associated_token_program,
+                system_program: &system_program,
+                payment_quote_price_feed_account: payment_quote_price_feed.as_ref(),
+            },
+            InstantBondRedemptionInstructionArgs { amount: bond_redeemed },
+        )
+        .invoke_signed(signer)?;
+        
+        accounts.fiat_reserve.reload()?;
+        let fiat_proceeds = accounts.fiat_reserve.amount.saturating_sub(fiat_before);
+        
+        // Matured bonds pay one unit of fiat each
+        let par = normalize_amount(bond_redeemed, accounts.bond_mint.decimals, accounts.fiat_token_mint.decimals)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        require!(fiat_proceeds >= par, StablecoinError::BondRedemptionBelowPar);
+        
+        // Buy the new issue with everything the redemption paid
+        PurchaseBondCpi::new(
+            &stablebond_program,
+            PurchaseBondCpiAccounts {
+                user_wallet: &coin_info,
+                bond_account: &new_bond_info,
+                issuance_account: &accounts.new_issuance.to_account_info(),
+                mint_account: &accounts.new_bond_mint.to_account_info(),
+                payment_account: &accounts.payment.to_account_info(),
+                payment_token_account: &accounts.payment_token_account.to_account_info(),
+                user_token_account: &accounts.new_bond_holding.to_account_info(),
+                user_payment_token_account: &fiat_reserve_info,
+                payment_mint_account: &fiat_mint_info,
+                payment_feed_account: &payment_feed,
+                payment_base_price_feed_account: &payment_base_price_feed,
+                token2022_program: &token_2022_program,
+                associated_token_program: &associated_token_program,
+                token_program: &token_program,
+                system_program: &system_program,
+                payment_quote_price_feed_account: payment_quote_price_feed.as_ref(),
+            },
+            PurchaseBondInstructionArgs { amount: fiat_proceeds },
+        )
+        .invoke_signed(signer)?;
+        
+        accounts.new_bond_holding.reload()?;
+        let bond_purchased = accounts.new_bond_holding.amount;
+        
+        // Carry the cached bond amounts over in proportion
+        let rescale = |amount: u64| -> Result<u64> {
+            let scaled = amount as u128 * bond_purchased as u128 / bond_redeemed as u128;
+            u64::try_from(scaled).map_err(|_| error!(StablecoinError::ArithmeticOverflow))
+        };
+        let sovereign_coin = &mut accounts.sovereign_coin;
+        let matured_bond_mint = sovereign_coin.bond_mint;
+        sovereign_coin.bond_amount = rescale(sovereign_coin.bond_amount)?;
+        sovereign_coin.protocol_owned_bond = rescale(sovereign_coin.protocol_owned_bond)?;
+        sovereign_coin.accrued_yield_bond = rescale(sovereign_coin.accrued_yield_bond)?;
+        sovereign_coin.bond_mint = new_bond_mint;
+        sovereign_coin.bond_account = new_bond_info.key();
+        sovereign_coin.bond_holding = accounts.new_bond_holding.key();
+        
+        // The coin now counts against the mapping's current mint
+        let mut factory = accounts.factory.load_mut()?;
+        let mapping = factory
+            .find_registered_mapping_mut(&sovereign_coin.target_fiat_currency)
+            .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+        mapping.previous_coin_count = mapping.previous_coin_count.checked_sub(1)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        mapping.coin_count = mapping.coin_count.checked_add(1)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        drop(factory);
+        
+        // Emit event
+        let cranker = accounts.cranker.key();
+        let sovereign_coin = accounts.sovereign_coin.key();
+        let new_bond_holding = accounts.new_bond_holding.key();
+        emit_cpi!(BondsRolledOverEvent {
+            cranker,
+            sovereign_coin,
+            matured_bond_mint,
+            new_bond_mint,
+            new_bond_holding,
+            bond_redeemed,
+            fiat_proceeds,
+            bond_purchased,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+use spl_token_2022::{onchain::invoke_transfer_checked, state::AccountState};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SeizeHolder<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &compliance_officer.key()) @ StablecoinError::Unauthorized
+    )]
+    pub compliance_officer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = mint,
+        constraint = sovereign_coin.permanent_delegate_enabled @ StablecoinError::PermanentDelegateNotEnabled,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> SeizeHolder<'info> {
+    /// Moves `amount` out of `source` as the mint's permanent delegate. Accounts
+    /// required by the compliance transfer hook, if any, go in remaining_accounts.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>, amount: u64, reason: String) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(reason.len() <= MAX_REASON_LEN, StablecoinError::ReasonTooLong);
+        
+        let accounts = &ctx.accounts;
+        let signer_seeds = accounts.sovereign_coin.signer_seeds();
+        
+        // Seizures usually follow a freeze, and frozen accounts cannot send
+        let was_frozen = accounts.source.state == AccountState::Frozen;
+        if was_frozen {
+            token_interface::thaw_account(CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token_interface::ThawAccount {
+                    account: accounts.source.to_account_info(),
+                    mint: accounts.mint.to_account_info(),
+                    authority: accounts.sovereign_coin.to_account_info(),
+                },
+                &[&signer_seeds],
+            ))?;
+        }
+        
+        invoke_transfer_checked(
+            &accounts.token_program.key(),
+            accounts.source.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.destination.to_account_info(),
+            accounts.sovereign_coin.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            accounts.mint.decimals,
+            &[&signer_seeds],
+        )?;
+        
+        if was_frozen {
+            token_interface::freeze_account(CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token_interface::FreezeAccount {
+                    account: accounts.source.to_account_info(),
+                    mint: accounts.mint.to_account_info(),
+                    authority: accounts.sovereign_coin.to_account_info(),
+                },
+                &[&signer_seeds],
+            ))?;
+        }
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(HolderSeizedEvent {
+            compliance_officer: accounts.compliance_officer.key(),
+            sovereign_coin: accounts.sovereign_coin.key(),
+            source: accounts.source.key(),
+            source_owner: accounts.source.owner,
+            destination: accounts.destination.key(),
+            amount,
+            reason,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCoinFees<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+}
+
+impl SetCoinFees<'_> {
+    pub fn handler(ctx: Context<Self>, tiers: Vec<FeeTier>) -> Result<()> {
+        FeeSchedule::validate(&tiers, ctx.accounts.factory.load()?.max_issuer_fee_bps)?;
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.fee_schedule = FeeSchedule::new(&tiers);
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(CoinFeesSetEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            tiers,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCoinLimits<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+}
+
+impl SetCoinLimits<'_> {
+    pub fn handler(ctx: Context<Self>, max_supply: u64, mint_limit: u64, redeem_limit: u64) -> Result<()> {
+        let factory = ctx.accounts.factory.load()?;
+        require!(
+            max_supply <= factory.coin_max_supply_cap
+                && mint_limit <= factory.coin_mint_limit_cap
+                && redeem_limit <= factory.coin_redeem_limit_cap,
+            StablecoinError::LimitAboveFactoryBound
+        );
+        
+        // Usage in the current window carries over to the new limits
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.max_supply = max_supply;
+        sovereign_coin.mint_limit.limit = mint_limit;
+        sovereign_coin.redeem_limit.limit = redeem_limit;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(CoinLimitsSetEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            max_supply,
+            mint_limit,
+            redeem_limit,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFlashMintConfig<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+}
+
+impl SetFlashMintConfig<'_> {
+    pub fn handler(ctx: Context<Self>, cap: u64, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.flash_mint_cap = cap;
+        sovereign_coin.flash_fee_bps = fee_bps;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(FlashMintConfigSetEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            cap,
+            fee_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPermissionless<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+}
+
+impl SetPermissionless<'_> {
+    pub fn handler(ctx: Context<Self>, permissionless: bool) -> Result<()> {
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.permissionless = permissionless;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(PermissionlessUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            permissionless,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use super::*;
+use anchor_lang::solana_program::program_option::COption;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleRedeem<'info> {
+    pub holder: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        mut,
+        constraint = mint.mint_authority == COption::Some(sovereign_coin.key()) @ StablecoinError::MintAuthorityNotDelegated,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+    )]
+    pub holder_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(address = sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint)]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut, token::mint = fiat_token_mint)]
+    pub holder_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut, token::mint = bond_token_mint)]
+    pub holder_bond_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub fiat_token_program: Interface<'info, TokenInterface>,
+    pub bond_token_program: Interface<'info, TokenInterface>,
+}
+
+impl SettleRedeem<'_> {
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        require!(sovereign_coin.winding_down, StablecoinError::SovereignCoinNotWindingDown);
+        sovereign_coin.require_not_paused(&*ctx.accounts.factory.load()?, PAUSE_REDEEM)?;
+        
+        // Pro-rata share of the reserves snapshotted at begin_wind_down,
+        // rounded down so the reserves can always cover every holder
+        let fiat_paid = pro_rata_share(amount, sovereign_coin.wind_down_fiat, sovereign_coin.wind_down_supply)?;
+        let bond_paid = pro_rata_share(amount, sovereign_coin.wind_down_bond, sovereign_coin.wind_down_supply)?;
+        
+        // Burn the holder's coins
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.holder_coin_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        // Pay out of the reserves as the coin PDA
+        let signer_seeds = sovereign_coin.signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        if fiat_paid > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.fiat_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.fiat_reserve.to_account_info(),
+                        mint: ctx.accounts.fiat_token_mint.to_account_info(),
+                        to: ctx.accounts.holder_fiat_account.to_account_info(),
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                fiat_paid,
+                ctx.accounts.fiat_token_mint.decimals,
+            )?;
+        }
+        if bond_paid > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.bond_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.bond_holding.to_account_info(),
+                        mint: ctx.accounts.bond_token_mint.to_account_info(),
+                        to: ctx.accounts.holder_bond_account.to_account_info(),
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                bond_paid,
+                ctx.accounts.bond_token_mint.decimals,
+            )?;
+        }
+        
+        // Keep cached amounts in step with what left the reserves
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.saturating_sub(amount);
+        sovereign_coin.fiat_amount = sovereign_coin.fiat_amount.saturating_sub(fiat_paid);
+        sovereign_coin.bond_amount = sovereign_coin.bond_amount.saturating_sub(bond_paid);
+        
+        // Settlements count towards the outflow window of the coin's currency
+        let clock = Clock::get()?;
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        let tripped = factory.record_outflow(&sovereign_coin.target_fiat_currency, amount, clock.unix_timestamp);
+        
+        // Emit event
+        emit_cpi!(SettleRedeemEvent {
+            holder: ctx.accounts.holder.key(),
+            sovereign_coin: sovereign_coin.key(),
+            amount_burned: amount,
+            fiat_paid,
+            bond_paid,
+            timestamp: clock.unix_timestamp,
+        });
+        if tripped {
+            emit_cpi!(CircuitBreakerTrippedEvent {
+                factory: ctx.accounts.factory.key(),
+                trip: factory.breaker_trip(),
+                flags: factory.breaker_flags,
+                paused: factory.paused,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        Ok(())
+    }
+}
+
+fn pro_rata_share(amount: u64, total: u64, supply: u64) -> Result<u64> {
+    require!(supply > 0, StablecoinError::InvalidAmount);
+    let share = (amount as u128)
+        .checked_mul(total as u128)
+        .ok_or(StablecoinError::ArithmeticOverflow)?
+        / supply as u128;
+    Ok(u64::try_from(share).map_err(|_| StablecoinError::ArithmeticOverflow)?)
+}
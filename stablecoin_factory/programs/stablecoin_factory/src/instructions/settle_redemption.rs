@@ -0,0 +1,202 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleRedemption<'info> {
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = mint,
+        has_one = fiat_reserve,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption_ticket", sovereign_coin.key().as_ref(), &ticket.id.to_le_bytes()],
+        bump = ticket.bump,
+        constraint = ticket.id == sovereign_coin.next_settle_ticket_id @ StablecoinError::TicketNotAtFront,
+    )]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+    
+    /// CHECK: Receives the ticket's rent
+    #[account(mut, address = ticket.owner)]
+    pub owner: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", sovereign_coin.key().as_ref()],
+        bump,
+    )]
+    pub redemption_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut, address = ticket.fiat_destination)]
+    pub fiat_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::authority = factory.load()?.treasury,
+    )]
+    pub treasury_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = fiat_token_mint,
+        token::authority = sovereign_coin.authority,
+    )]
+    pub issuer_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Required while the factory routes part of the protocol fee to insurance
+    #[account(
+        mut,
+        seeds = [b"insurance_vault", sovereign_coin.fiat_currency_code()],
+        bump,
+        token::mint = fiat_token_mint,
+    )]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub fiat_token_program: Interface<'info, TokenInterface>,
+}
+
+impl SettleRedemption<'_> {
+    /// Crank: settles the ticket at the front of the queue once the fiat
+    /// reserve can pay it at par, or closes it if it was cancelled. Only
+    /// cancelled tickets are closed once the coin is winding down.
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        
+        if ctx.accounts.ticket.status == TicketStatus::Cancelled {
+            ctx.accounts.sovereign_coin.next_settle_ticket_id += 1;
+            return ctx.accounts.ticket.close(ctx.accounts.owner.to_account_info());
+        }
+        
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        sovereign_coin.require_not_paused(&factory, PAUSE_REDEEM)?;
+        // Paying at par would shrink the wind-down snapshot for everyone else;
+        // holders cancel their tickets and redeem pro rata instead
+        require!(!sovereign_coin.winding_down, StablecoinError::SovereignCoinWindingDown);
+        
+        // Each coin is worth one unit of its fiat, with the interest accrued
+        // up to settlement
+        let ticket = &ctx.accounts.ticket;
+        let fiat_decimals = ctx.accounts.fiat_token_mint.decimals;
+        let payout = RedemptionPayout::new(
+            &factory,
+            &ticket.fees,
+            &ctx.accounts.mint.to_account_info(),
+            sovereign_coin.decimals,
+            fiat_decimals,
+            clock.unix_timestamp,
+        )?;
+        let fiat_total = payout.total();
+        require!(
+            sovereign_coin.backing_fiat(ctx.accounts.fiat_reserve.amount) >= fiat_total,
+            StablecoinError::InsufficientFiatReserve
+        );
+        
+        let signer_seeds = sovereign_coin.signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        
+        // Burn the escrowed coins
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.redemption_escrow.to_account_info(),
+                    authority: sovereign_coin.to_account_info(),
+                },
+                signer,
+            ),
+            ticket.amount,
+        )?;
+        
+        // Pay the holder and the fees out of the fiat reserve, the insurance
+        // share of the protocol fee to the currency's vault
+        for (destination, amount) in payout.transfers(
+            ctx.accounts.fiat_destination.to_account_info(),
+            ctx.accounts.treasury_fiat_account.to_account_info(),
+            ctx.accounts.insurance_vault.as_ref().map(|vault| vault.to_account_info()),
+            ctx.accounts.issuer_fiat_account.to_account_info(),
+        )? {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.fiat_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.fiat_reserve.to_account_info(),
+                        mint: ctx.accounts.fiat_token_mint.to_account_info(),
+                        to: destination,
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+                fiat_decimals,
+            )?;
+        }
+        
+        let amount = ticket.amount;
+        let fees = ticket.fees;
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.record_redeem(&factory, amount, clock.unix_timestamp)?;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.saturating_sub(amount);
+        sovereign_coin.fiat_amount = sovereign_coin.fiat_amount.saturating_sub(fiat_total);
+        sovereign_coin.queued_redemptions = sovereign_coin.queued_redemptions.saturating_sub(amount);
+        sovereign_coin.next_settle_ticket_id += 1;
+        
+        let tripped = factory.record_outflow(&sovereign_coin.target_fiat_currency, amount, clock.unix_timestamp);
+        
+        ctx.accounts.ticket.close(ctx.accounts.owner.to_account_info())?;
+        
+        // Emit event
+        emit_cpi!(RedemptionSettledEvent {
+            owner: ctx.accounts.owner.key(),
+            sovereign_coin: sovereign_coin.key(),
+            ticket: ctx.accounts.ticket.key(),
+            id: ctx.accounts.ticket.id,
+            amount,
+            fiat_paid: payout.fiat_paid,
+            insurance_fee: payout.insurance_fee,
+            timestamp: clock.unix_timestamp,
+        });
+        emit_cpi!(FeesChargedEvent {
+            payer: ctx.accounts.owner.key(),
+            sovereign_coin: sovereign_coin.key(),
+            operation: FeeOperation::Redeem,
+            fees,
+            timestamp: clock.unix_timestamp,
+        });
+        if tripped {
+            emit_cpi!(CircuitBreakerTrippedEvent {
+                factory: ctx.accounts.factory.key(),
+                trip: factory.breaker_trip(),
+                flags: factory.breaker_flags,
+                paused: factory.paused,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        Ok(())
+    }
+}
@@ -10,18 +10,19 @@ pub struct SetupMint<'info> {
     
     #[account(
         mut,
-        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol.as_ref()],
+        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol_seed()],
         bump = sovereign_coin.bump,
         constraint = sovereign_coin.authority == authority.key()
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
     
-    // Initialize just the mint
+    // Initialize just the mint; only program paths can issue coins
     #[account(
         init,
         payer = payer,
         mint::decimals = 6,
-        mint::authority = authority,
+        mint::authority = sovereign_coin,
+        mint::freeze_authority = sovereign_coin,
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -36,11 +37,19 @@ impl SetupMint<'_> {
         // Set mint account
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
         sovereign_coin.mint = ctx.accounts.mint.key();
+        sovereign_coin.metadata_standard = MetadataStandard::Metaplex;
+        sovereign_coin.transfer_hook_enabled = false;
+        sovereign_coin.interest_bearing = false;
+        sovereign_coin.permanent_delegate_enabled = false;
 
         // Emit event
         let clock = Clock::get()?;
         emit_cpi!(SovereignCoinSetupMintEvent {
             mint: sovereign_coin.mint,
+            metadata_standard: sovereign_coin.metadata_standard,
+            transfer_hook_enabled: sovereign_coin.transfer_hook_enabled,
+            interest_bearing: sovereign_coin.interest_bearing,
+            permanent_delegate_enabled: sovereign_coin.permanent_delegate_enabled,
             timestamp: clock.unix_timestamp,
         });
         
@@ -0,0 +1,158 @@
+use super::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use spl_token_2022::{extension::ExtensionType, state::Mint as MintState};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Token2022MintArgs {
+    pub transfer_hook: bool,     // Check transfers with the compliance hook program
+    pub interest_bearing: bool,  // Pass holder yield into displayed balances
+    pub permanent_delegate: bool, // Let the coin PDA seize balances under court order
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetupToken2022Mint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.authority == authority.key()
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    // Created here, since the extensions must be initialized before the mint
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    
+    // System programs needed for this specific operation
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl SetupToken2022Mint<'_> {
+    pub fn handler(ctx: Context<Self>, args: Token2022MintArgs) -> Result<()> {
+        let accounts = &ctx.accounts;
+        
+        // Allocate for the extensions only; the TokenMetadata entry is added
+        // by finalize_setup, which tops up rent for it
+        let mut extensions = vec![ExtensionType::MetadataPointer];
+        if args.transfer_hook {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        if args.interest_bearing {
+            extensions.push(ExtensionType::InterestBearingConfig);
+        }
+        if args.permanent_delegate {
+            extensions.push(ExtensionType::PermanentDelegate);
+        }
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+        create_account(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: accounts.payer.to_account_info(),
+                    to: accounts.mint.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &accounts.token_program.key(),
+        )?;
+        
+        // Metadata lives on the mint itself
+        token_interface::metadata_pointer_initialize(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                token_interface::MetadataPointerInitialize {
+                    token_program_id: accounts.token_program.to_account_info(),
+                    mint: accounts.mint.to_account_info(),
+                },
+            ),
+            Some(accounts.authority.key()),
+            Some(accounts.mint.key()),
+        )?;
+        
+        // Route every transfer through the compliance hook program
+        if args.transfer_hook {
+            token_interface::transfer_hook_initialize(
+                CpiContext::new(
+                    accounts.token_program.to_account_info(),
+                    token_interface::TransferHookInitialize {
+                        token_program_id: accounts.token_program.to_account_info(),
+                        mint: accounts.mint.to_account_info(),
+                    },
+                ),
+                Some(accounts.authority.key()),
+                Some(COMPLIANCE_HOOK_PROGRAM_ID),
+            )?;
+        }
+        
+        // Starts at zero; only harvest_yield, signed by the coin PDA, sets the rate
+        if args.interest_bearing {
+            token_interface::interest_bearing_mint_initialize(
+                CpiContext::new(
+                    accounts.token_program.to_account_info(),
+                    token_interface::InterestBearingMintInitialize {
+                        token_program_id: accounts.token_program.to_account_info(),
+                        mint: accounts.mint.to_account_info(),
+                    },
+                ),
+                Some(accounts.sovereign_coin.key()),
+                0,
+            )?;
+        }
+        
+        // Only seize_holder, signed by the coin PDA, can use the delegate
+        if args.permanent_delegate {
+            token_interface::permanent_delegate_initialize(
+                CpiContext::new(
+                    accounts.token_program.to_account_info(),
+                    token_interface::PermanentDelegateInitialize {
+                        token_program_id: accounts.token_program.to_account_info(),
+                        mint: accounts.mint.to_account_info(),
+                    },
+                ),
+                &accounts.sovereign_coin.key(),
+            )?;
+        }
+        
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                token_interface::InitializeMint2 {
+                    mint: accounts.mint.to_account_info(),
+                },
+            ),
+            accounts.sovereign_coin.decimals,
+            &accounts.sovereign_coin.key(),
+            Some(&accounts.sovereign_coin.key()),
+        )?;
+        
+        // Set mint account
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.mint = ctx.accounts.mint.key();
+        sovereign_coin.metadata_standard = MetadataStandard::Token2022;
+        sovereign_coin.transfer_hook_enabled = args.transfer_hook;
+        sovereign_coin.interest_bearing = args.interest_bearing;
+        sovereign_coin.permanent_delegate_enabled = args.permanent_delegate;
+        sovereign_coin.last_yield_harvest_at = Clock::get()?.unix_timestamp;
+
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(SovereignCoinSetupMintEvent {
+            mint: sovereign_coin.mint,
+            metadata_standard: sovereign_coin.metadata_standard,
+            transfer_hook_enabled: sovereign_coin.transfer_hook_enabled,
+            interest_bearing: sovereign_coin.interest_bearing,
+            permanent_delegate_enabled: sovereign_coin.permanent_delegate_enabled,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -10,18 +10,18 @@ pub struct SetupTokenAccounts<'info> {
     
     #[account(
         mut,
-        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol.as_ref()],
+        seeds = [b"sovereign_coin", authority.key().as_ref(), sovereign_coin.symbol_seed()],
         bump = sovereign_coin.bump,
         constraint = sovereign_coin.authority == authority.key()
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
     
-    // SPL Token accounts
+    // SPL Token accounts, owned by the coin PDA so only the program moves reserves
     #[account(
         init,
         payer = payer,
         token::mint = fiat_token_mint,
-        token::authority = authority,
+        token::authority = sovereign_coin,
     )]
     pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
     
@@ -29,7 +29,7 @@ pub struct SetupTokenAccounts<'info> {
         init,
         payer = payer,
         token::mint = bond_token_mint,
-        token::authority = authority,
+        token::authority = sovereign_coin,
     )]
     pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
     
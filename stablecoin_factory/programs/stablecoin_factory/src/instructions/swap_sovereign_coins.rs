@@ -0,0 +1,321 @@
+use super::*;
+use anchor_lang::solana_program::program_option::COption;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapSovereignCoins<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"fx_rate", fx_rate.base_code(), fx_rate.quote_code()],
+        bump = fx_rate.bump,
+    )]
+    pub fx_rate: Box<Account<'info, FxRate>>,
+    
+    /// CHECK: Signing PDA that owns the FX pool token accounts
+    #[account(
+        seeds = [b"fx_pool", factory.key().as_ref()],
+        bump,
+    )]
+    pub fx_pool: UncheckedAccount<'info>,
+    
+    // Coin being burned
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin_in.authority.as_ref(), sovereign_coin_in.symbol_seed()],
+        bump = sovereign_coin_in.bump,
+        has_one = factory,
+        constraint = sovereign_coin_in.mint == mint_in.key() @ StablecoinError::InvalidSovereignCoinAccount,
+        constraint = sovereign_coin_in.fiat_reserve == fiat_reserve_in.key() @ StablecoinError::InvalidSovereignCoinAccount,
+    )]
+    pub sovereign_coin_in: Box<Account<'info, SovereignCoin>>,
+    
+    // Coin being minted
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin_out.authority.as_ref(), sovereign_coin_out.symbol_seed()],
+        bump = sovereign_coin_out.bump,
+        has_one = factory,
+        constraint = sovereign_coin_out.key() != sovereign_coin_in.key() @ StablecoinError::SameSovereignCoin,
+        constraint = sovereign_coin_out.mint == mint_out.key() @ StablecoinError::InvalidSovereignCoinAccount,
+        constraint = sovereign_coin_out.fiat_reserve == fiat_reserve_out.key() @ StablecoinError::InvalidSovereignCoinAccount,
+    )]
+    pub sovereign_coin_out: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(mut)]
+    pub mint_in: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = mint_out.mint_authority == COption::Some(sovereign_coin_out.key()) @ StablecoinError::MintAuthorityNotDelegated,
+    )]
+    pub mint_out: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint_in,
+        token::authority = user,
+        token::token_program = coin_in_token_program,
+    )]
+    pub user_coin_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint_out,
+        token::token_program = coin_out_token_program,
+    )]
+    pub user_coin_out: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub fiat_reserve_out: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve_in.mint)]
+    pub fiat_mint_in: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(address = fiat_reserve_out.mint)]
+    pub fiat_mint_out: Box<InterfaceAccount<'info, Mint>>,
+    
+    // Receives the fiat released by the burned coins
+    #[account(
+        mut,
+        token::mint = fiat_mint_in,
+        token::authority = fx_pool,
+    )]
+    pub pool_fiat_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // Supplies the fiat backing the minted coins
+    #[account(
+        mut,
+        token::mint = fiat_mint_out,
+        token::authority = fx_pool,
+    )]
+    pub pool_fiat_out: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    // A swap redeems one coin and mints the other, so each coin that is not
+    // permissionless needs the user on its allowlist
+    #[account(
+        seeds = [b"primary_market_allowlist", sovereign_coin_in.key().as_ref(), user.key().as_ref()],
+        bump = allowlist_entry_in.bump,
+    )]
+    pub allowlist_entry_in: Option<Box<Account<'info, PrimaryMarketAllowlist>>>,
+    
+    #[account(
+        seeds = [b"primary_market_allowlist", sovereign_coin_out.key().as_ref(), user.key().as_ref()],
+        bump = allowlist_entry_out.bump,
+    )]
+    pub allowlist_entry_out: Option<Box<Account<'info, PrimaryMarketAllowlist>>>,
+    
+    pub coin_in_token_program: Interface<'info, TokenInterface>,
+    pub coin_out_token_program: Interface<'info, TokenInterface>,
+    pub fiat_in_token_program: Interface<'info, TokenInterface>,
+    pub fiat_out_token_program: Interface<'info, TokenInterface>,
+}
+
+impl SwapSovereignCoins<'_> {
+    /// Burns `amount_in` of one coin and mints the other at the FX rate, less
+    /// the factory swap fee. The burned coins' fiat moves from their reserve to
+    /// the FX pool, and the pool funds the new coins' reserve one-for-one, so
+    /// the fee stays in the pool.
+    pub fn handler(ctx: Context<Self>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        require!(amount_in > 0, StablecoinError::InvalidAmount);
+        
+        let clock = Clock::get()?;
+        let accounts = &ctx.accounts;
+        let factory = accounts.factory.load()?;
+        let coin_in = &accounts.sovereign_coin_in;
+        let coin_out = &accounts.sovereign_coin_out;
+        coin_in.require_not_paused(&factory, PAUSE_REDEEM)?;
+        coin_out.require_not_paused(&factory, PAUSE_MINT)?;
+        let swap_fee_bps = factory.swap_fee_bps;
+        drop(factory);
+        
+        // A wound-down coin's holders settle pro-rata, not one-for-one
+        require!(
+            !coin_in.winding_down && !coin_out.winding_down,
+            StablecoinError::SovereignCoinWindingDown
+        );
+        coin_in.require_primary_market_access(accounts.allowlist_entry_in.is_some())?;
+        coin_out.require_primary_market_access(accounts.allowlist_entry_out.is_some())?;
+        
+        // The rate may be quoted either way round
+        let fx_rate = &accounts.fx_rate;
+        let inverse = if fx_rate.base_code() == coin_in.fiat_currency_code()
+            && fx_rate.quote_code() == coin_out.fiat_currency_code()
+        {
+            false
+        } else if fx_rate.base_code() == coin_out.fiat_currency_code()
+            && fx_rate.quote_code() == coin_in.fiat_currency_code()
+        {
+            true
+        } else {
+            return err!(StablecoinError::FxRateMismatch);
+        };
+        let price = load_fx_rate(fx_rate, clock.unix_timestamp)?;
+        
+        // The rate applies to the amounts holders see, interest included
+        let mint_in_info = accounts.mint_in.to_account_info();
+        let mint_out_info = accounts.mint_out.to_account_info();
+        let value_in = scaled_amount(&mint_in_info, amount_in, clock.unix_timestamp)?;
+        let gross_value_out = convert_at_price(value_in, coin_in.decimals, coin_out.decimals, &price, inverse)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let gross_out = unscaled_amount(&mint_out_info, gross_value_out, clock.unix_timestamp)?;
+        let fee = (gross_out as u128 * swap_fee_bps as u128).div_ceil(10_000) as u64;
+        let amount_out = gross_out - fee;
+        require!(amount_out > 0, StablecoinError::InvalidAmount);
+        require!(amount_out >= min_amount_out, StablecoinError::SlippageExceeded);
+        
+        // Each coin is worth one unit of its fiat
+        let value_out = scaled_amount(&mint_out_info, amount_out, clock.unix_timestamp)?;
+        let fiat_released = normalize_amount(value_in, coin_in.decimals, accounts.fiat_mint_in.decimals)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let fiat_required = normalize_amount(value_out, coin_out.decimals, accounts.fiat_mint_out.decimals)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Both coins must keep their fiat reserve requirement afterwards
+        let fiat_in_after = coin_in
+            .backing_fiat(accounts.fiat_reserve_in.amount)
+            .checked_sub(fiat_released)
+            .ok_or(StablecoinError::FiatReserveBelowRequirement)?;
+        let supply_in_after = scaled_amount(
+            &mint_in_info,
+            accounts.mint_in.supply.saturating_sub(amount_in),
+            clock.unix_timestamp,
+        )?;
+        let fiat_out_after = coin_out
+            .backing_fiat(accounts.fiat_reserve_out.amount)
+            .checked_add(fiat_required)
+            .ok_or(StablecoinError::ArithmeticOverflow)?;
+        let supply_out_after = scaled_amount(
+            &mint_out_info,
+            accounts.mint_out.supply
+                .checked_add(amount_out)
+                .ok_or(StablecoinError::ArithmeticOverflow)?,
+            clock.unix_timestamp,
+        )?;
+        for (fiat, fiat_decimals, supply, coin) in [
+            (fiat_in_after, accounts.fiat_mint_in.decimals, supply_in_after, coin_in),
+            (fiat_out_after, accounts.fiat_mint_out.decimals, supply_out_after, coin_out),
+        ] {
+            let sufficient = meets_fiat_reserve(fiat, fiat_decimals, supply, coin.decimals, coin.required_reserve_percentage)
+                .ok_or(StablecoinError::ArithmeticOverflow)?;
+            require!(sufficient, StablecoinError::FiatReserveBelowRequirement);
+        }
+        
+        // Burn the user's coins
+        token_interface::burn(
+            CpiContext::new(
+                accounts.coin_in_token_program.to_account_info(),
+                Burn {
+                    mint: accounts.mint_in.to_account_info(),
+                    from: accounts.user_coin_in.to_account_info(),
+                    authority: accounts.user.to_account_info(),
+                },
+            ),
+            amount_in,
+        )?;
+        
+        // Released fiat goes to the pool as the burned coin's PDA
+        let coin_in_seeds = coin_in.signer_seeds();
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.fiat_in_token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.fiat_reserve_in.to_account_info(),
+                    mint: accounts.fiat_mint_in.to_account_info(),
+                    to: accounts.pool_fiat_in.to_account_info(),
+                    authority: coin_in.to_account_info(),
+                },
+                &[&coin_in_seeds],
+            ),
+            fiat_released,
+            accounts.fiat_mint_in.decimals,
+        )?;
+        
+        // The pool funds the minted coin's reserve
+        let factory_key = accounts.factory.key();
+        let pool_seeds: &[&[u8]] = &[b"fx_pool", factory_key.as_ref(), &[ctx.bumps.fx_pool]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.fiat_out_token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.pool_fiat_out.to_account_info(),
+                    mint: accounts.fiat_mint_out.to_account_info(),
+                    to: accounts.fiat_reserve_out.to_account_info(),
+                    authority: accounts.fx_pool.to_account_info(),
+                },
+                &[pool_seeds],
+            ),
+            fiat_required,
+            accounts.fiat_mint_out.decimals,
+        )?;
+        
+        // Mint the other coin as its PDA
+        let coin_out_seeds = coin_out.signer_seeds();
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                accounts.coin_out_token_program.to_account_info(),
+                token_interface::MintTo {
+                    mint: accounts.mint_out.to_account_info(),
+                    to: accounts.user_coin_out.to_account_info(),
+                    authority: coin_out.to_account_info(),
+                },
+                &[&coin_out_seeds],
+            ),
+            amount_out,
+        )?;
+        
+        // Limits and cached amounts on both sides
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        let supply_out_before = ctx.accounts.mint_out.supply;
+        let coin_in = &mut ctx.accounts.sovereign_coin_in;
+        let coin_in_currency = coin_in.target_fiat_currency;
+        coin_in.record_redeem(&factory, amount_in, clock.unix_timestamp)?;
+        coin_in.total_supply = coin_in.total_supply.saturating_sub(amount_in);
+        coin_in.fiat_amount = coin_in.fiat_amount.saturating_sub(fiat_released);
+        
+        let coin_out = &mut ctx.accounts.sovereign_coin_out;
+        coin_out.record_mint(&factory, amount_out, supply_out_before, clock.unix_timestamp)?;
+        coin_out.total_supply = coin_out.total_supply.saturating_add(amount_out);
+        coin_out.fiat_amount = coin_out.fiat_amount.saturating_add(fiat_required);
+        
+        // The burned coins' fiat left their reserve, so it counts towards their
+        // currency's outflow window like any other redemption
+        let tripped = factory.record_outflow(&coin_in_currency, amount_in, clock.unix_timestamp);
+        
+        // Emit event
+        emit_cpi!(SovereignCoinsSwappedEvent {
+            user: ctx.accounts.user.key(),
+            sovereign_coin_in: ctx.accounts.sovereign_coin_in.key(),
+            sovereign_coin_out: ctx.accounts.sovereign_coin_out.key(),
+            amount_in,
+            amount_out,
+            fee,
+            fx_price: price.price,
+            fx_expo: price.expo,
+            fiat_released,
+            fiat_required,
+            timestamp: clock.unix_timestamp,
+        });
+        if tripped {
+            emit_cpi!(CircuitBreakerTrippedEvent {
+                factory: ctx.accounts.factory.key(),
+                trip: factory.breaker_trip(),
+                flags: factory.breaker_flags,
+                paused: factory.paused,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SyncReserves<'info> {
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl SyncReserves<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        // Reconciliation can accrue yield
+        let factory = ctx.accounts.factory.load()?;
+        ctx.accounts.sovereign_coin.require_not_paused(&factory, PAUSE_YIELD)?;
+        
+        let policy = factory.surplus_policy();
+        let fiat_actual = ctx.accounts.fiat_reserve.amount;
+        let bond_actual = ctx.accounts.bond_holding.amount;
+        
+        let sovereign_coin_key = ctx.accounts.sovereign_coin.key();
+        let sovereign_coin: &mut SovereignCoin = &mut ctx.accounts.sovereign_coin;
+        let fiat_cached = sovereign_coin.fiat_amount;
+        let bond_cached = sovereign_coin.bond_amount;
+        
+        let fiat = reconcile_reserve(
+            &mut sovereign_coin.fiat_amount,
+            &mut sovereign_coin.protocol_owned_fiat,
+            &mut sovereign_coin.accrued_yield_fiat,
+            fiat_actual,
+            policy,
+        ).ok_or(StablecoinError::ArithmeticOverflow)?;
+        let bond = reconcile_reserve(
+            &mut sovereign_coin.bond_amount,
+            &mut sovereign_coin.protocol_owned_bond,
+            &mut sovereign_coin.accrued_yield_bond,
+            bond_actual,
+            policy,
+        ).ok_or(StablecoinError::ArithmeticOverflow)?;
+        
+        // Only discrepancies are worth reporting
+        if fiat.surplus == 0 && fiat.shortfall == 0 && bond.surplus == 0 && bond.shortfall == 0 {
+            return Ok(());
+        }
+        
+        let clock = Clock::get()?;
+        emit_cpi!(ReserveDiscrepancyEvent {
+            sovereign_coin: sovereign_coin_key,
+            surplus_policy: policy,
+            fiat_cached,
+            fiat_actual,
+            fiat_surplus: fiat.surplus,
+            fiat_shortfall: fiat.shortfall,
+            bond_cached,
+            bond_actual,
+            bond_surplus: bond.surplus,
+            bond_shortfall: bond.shortfall,
+            fiat_amount: sovereign_coin.fiat_amount,
+            bond_amount: sovereign_coin.bond_amount,
+            accrued_yield_fiat: sovereign_coin.accrued_yield_fiat,
+            accrued_yield_bond: sovereign_coin.accrued_yield_bond,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
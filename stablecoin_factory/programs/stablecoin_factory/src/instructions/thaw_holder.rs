@@ -0,0 +1,66 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ThawHolder<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::ComplianceOfficer, &compliance_officer.key()) @ StablecoinError::Unauthorized
+    )]
+    pub compliance_officer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = mint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ThawHolder<'_> {
+    pub fn handler(ctx: Context<Self>, reason: String) -> Result<()> {
+        require!(reason.len() <= MAX_REASON_LEN, StablecoinError::ReasonTooLong);
+        
+        let accounts = &ctx.accounts;
+        let signer_seeds = accounts.sovereign_coin.signer_seeds();
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            token_interface::ThawAccount {
+                account: accounts.holder_token_account.to_account_info(),
+                mint: accounts.mint.to_account_info(),
+                authority: accounts.sovereign_coin.to_account_info(),
+            },
+            &[&signer_seeds],
+        ))?;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(HolderFreezeUpdatedEvent {
+            compliance_officer: accounts.compliance_officer.key(),
+            sovereign_coin: accounts.sovereign_coin.key(),
+            token_account: accounts.holder_token_account.key(),
+            owner: accounts.holder_token_account.owner,
+            frozen: false,
+            reason,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnpauseFactory<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+}
+
+impl UnpauseFactory<'_> {
+    pub fn handler(ctx: Context<Self>, flags: u8) -> Result<()> {
+        require!(flags != 0 && flags & !PAUSE_ALL == 0, StablecoinError::InvalidPauseFlags);
+        
+        let mut factory = ctx.accounts.factory.load_mut()?;
+        factory.paused &= !flags;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(FactoryPauseUpdatedEvent {
+            signer: ctx.accounts.authority.key(),
+            factory: ctx.accounts.factory.key(),
+            flags,
+            paused: factory.paused,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
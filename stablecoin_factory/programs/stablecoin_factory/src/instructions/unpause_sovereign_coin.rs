@@ -0,0 +1,57 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnpauseSovereignCoin<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::IssuerAdmin, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+}
+
+impl UnpauseSovereignCoin<'_> {
+    pub fn handler(ctx: Context<Self>, flags: u8) -> Result<()> {
+        require!(flags != 0 && flags & !PAUSE_ALL == 0, StablecoinError::InvalidPauseFlags);
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        
+        // Minting stays frozen for the rest of a wind-down
+        require!(
+            !(sovereign_coin.winding_down && flags & PAUSE_MINT != 0),
+            StablecoinError::SovereignCoinWindingDown
+        );
+        sovereign_coin.paused &= !flags;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(SovereignCoinPauseUpdatedEvent {
+            signer: ctx.accounts.authority.key(),
+            sovereign_coin: sovereign_coin.key(),
+            flags,
+            paused: sovereign_coin.paused,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
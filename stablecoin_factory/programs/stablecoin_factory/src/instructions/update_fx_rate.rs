@@ -0,0 +1,55 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFxRate<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::RateOracleUpdater, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"fx_rate", fx_rate.base_code(), fx_rate.quote_code()],
+        bump = fx_rate.bump,
+    )]
+    pub fx_rate: Box<Account<'info, FxRate>>,
+}
+
+impl UpdateFxRate<'_> {
+    pub fn handler(ctx: Context<Self>, price: i64, conf: u64) -> Result<()> {
+        require!(price > 0, StablecoinError::InvalidOraclePrice);
+        
+        let clock = Clock::get()?;
+        let fx_rate = &mut ctx.accounts.fx_rate;
+        fx_rate.price = price;
+        fx_rate.conf = conf;
+        fx_rate.publish_time = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(FxRateUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            fx_rate: fx_rate.key(),
+            base_currency: fx_rate.base_currency,
+            quote_currency: fx_rate.quote_currency,
+            price,
+            conf,
+            expo: fx_rate.expo,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateManualPrice<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::RateOracleUpdater, &authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"manual_price", manual_price.bond_mint.as_ref()],
+        bump = manual_price.bump,
+    )]
+    pub manual_price: Box<Account<'info, ManualPrice>>,
+}
+
+impl UpdateManualPrice<'_> {
+    pub fn handler(ctx: Context<Self>, price: i64, conf: u64) -> Result<()> {
+        require!(price > 0, StablecoinError::InvalidOraclePrice);
+        
+        let clock = Clock::get()?;
+        let manual_price = &mut ctx.accounts.manual_price;
+        manual_price.price = price;
+        manual_price.conf = conf;
+        manual_price.publish_time = clock.unix_timestamp;
+        
+        // Emit event
+        emit_cpi!(ManualPriceUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            manual_price: manual_price.key(),
+            bond_mint: manual_price.bond_mint,
+            price,
+            conf,
+            expo: manual_price.expo,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
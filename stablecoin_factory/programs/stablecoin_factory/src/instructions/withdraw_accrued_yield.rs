@@ -0,0 +1,123 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawAccruedYield<'info> {
+    #[account(
+        constraint = coin_roles.has_role(Role::Treasurer, &treasurer.key()) @ StablecoinError::Unauthorized
+    )]
+    pub treasurer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin", sovereign_coin.authority.as_ref(), sovereign_coin.symbol_seed()],
+        bump = sovereign_coin.bump,
+        has_one = factory,
+        has_one = fiat_reserve,
+        has_one = bond_holding,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+    
+    #[account(
+        seeds = [b"roles", sovereign_coin.key().as_ref()],
+        bump = coin_roles.bump,
+    )]
+    pub coin_roles: Box<Account<'info, Roles>>,
+    
+    #[account(mut)]
+    pub fiat_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = fiat_reserve.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(address = sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint)]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut, token::mint = fiat_token_mint)]
+    pub destination_fiat_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut, token::mint = bond_token_mint)]
+    pub destination_bond_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub fiat_token_program: Interface<'info, TokenInterface>,
+    pub bond_token_program: Interface<'info, TokenInterface>,
+}
+
+impl WithdrawAccruedYield<'_> {
+    pub fn handler(ctx: Context<Self>, fiat_amount: u64, bond_amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let sovereign_coin = &accounts.sovereign_coin;
+        // Still allowed during a wind-down: the snapshot holders settle against
+        // leaves the accrued yield out, so withdrawing it does not touch them
+        sovereign_coin.require_not_paused(&*accounts.factory.load()?, PAUSE_YIELD)?;
+        
+        require!(fiat_amount > 0 || bond_amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            fiat_amount <= sovereign_coin.accrued_yield_fiat && bond_amount <= sovereign_coin.accrued_yield_bond,
+            StablecoinError::InsufficientAccruedYield
+        );
+        
+        let signer_seeds = sovereign_coin.signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        
+        if fiat_amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.fiat_token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.fiat_reserve.to_account_info(),
+                        mint: accounts.fiat_token_mint.to_account_info(),
+                        to: accounts.destination_fiat_account.to_account_info(),
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                fiat_amount,
+                accounts.fiat_token_mint.decimals,
+            )?;
+        }
+        if bond_amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.bond_token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.bond_holding.to_account_info(),
+                        mint: accounts.bond_token_mint.to_account_info(),
+                        to: accounts.destination_bond_account.to_account_info(),
+                        authority: sovereign_coin.to_account_info(),
+                    },
+                    signer,
+                ),
+                bond_amount,
+                accounts.bond_token_mint.decimals,
+            )?;
+        }
+        
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.accrued_yield_fiat -= fiat_amount;
+        sovereign_coin.accrued_yield_bond -= bond_amount;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(AccruedYieldWithdrawnEvent {
+            treasurer: ctx.accounts.treasurer.key(),
+            sovereign_coin: sovereign_coin.key(),
+            fiat_amount,
+            bond_amount,
+            accrued_yield_fiat: sovereign_coin.accrued_yield_fiat,
+            accrued_yield_bond: sovereign_coin.accrued_yield_bond,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFxPool<'info> {
+    #[account(
+        constraint = factory_roles.has_role(Role::Treasurer, &treasurer.key()) @ StablecoinError::Unauthorized
+    )]
+    pub treasurer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.load()?.bump,
+    )]
+    pub factory: AccountLoader<'info, Factory>,
+    
+    #[account(
+        seeds = [b"roles", factory.key().as_ref()],
+        bump = factory_roles.bump,
+    )]
+    pub factory_roles: Box<Account<'info, Roles>>,
+    
+    /// CHECK: Signing PDA that owns the FX pool token accounts
+    #[account(
+        seeds = [b"fx_pool", factory.key().as_ref()],
+        bump,
+    )]
+    pub fx_pool: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::authority = fx_pool,
+        token::token_program = token_program,
+    )]
+    pub pool_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = pool_account.mint)]
+    pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut, token::mint = fiat_token_mint)]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl WithdrawFxPool<'_> {
+    /// Anyone can fund the pool by transferring to a token account owned by the
+    /// fx_pool PDA; only the factory treasurer can take liquidity out.
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(!ctx.accounts.factory.load()?.is_paused(PAUSE_REDEEM), StablecoinError::FactoryPaused);
+        
+        let factory_key = ctx.accounts.factory.key();
+        let signer_seeds: &[&[u8]] = &[b"fx_pool", factory_key.as_ref(), &[ctx.bumps.fx_pool]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.pool_account.to_account_info(),
+                    mint: ctx.accounts.fiat_token_mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.fx_pool.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+            ctx.accounts.fiat_token_mint.decimals,
+        )?;
+        
+        // Emit event
+        let clock = Clock::get()?;
+        emit_cpi!(FxPoolWithdrawnEvent {
+            treasurer: ctx.accounts.treasurer.key(),
+            pool_account: ctx.accounts.pool_account.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+}
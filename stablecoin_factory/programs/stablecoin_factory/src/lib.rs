@@ -3,9 +3,9 @@ use anchor_spl::metadata::{
     create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
     Metadata,
 };
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_interface::{self, Burn, Mint, MintTo, TokenInterface, TokenAccount, Transfer},
+use anchor_spl::token_2022::{spl_token_2022, Token2022};
+use anchor_spl::token_interface::{
+    self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 use stablebond_sdk::find_bond_pda;
 
@@ -88,6 +88,12 @@ pub mod stablecoin_factory {
         SetupMint::handler(ctx)
     }
 
+    /// Alternative to setup_mint: a Token-2022 mint that stores its own metadata,
+    /// optionally with a compliance transfer hook and an interest-bearing balance
+    pub fn setup_token_2022_mint(ctx: Context<SetupToken2022Mint>, args: Token2022MintArgs) -> Result<()> {
+        SetupToken2022Mint::handler(ctx, args)
+    }
+
     pub fn setup_token_accounts(ctx: Context<SetupTokenAccounts>) -> Result<()> {
         SetupTokenAccounts::handler(ctx)
     }
@@ -95,4 +101,286 @@ pub mod stablecoin_factory {
     pub fn finalize_setup(ctx: Context<FinalizeSetup>) -> Result<()> {
         FinalizeSetup::handler(ctx)
     }
+
+    pub fn init_manual_price(ctx: Context<InitManualPrice>, expo: i32) -> Result<()> {
+        InitManualPrice::handler(ctx, expo)
+    }
+
+    pub fn update_manual_price(ctx: Context<UpdateManualPrice>, price: i64, conf: u64) -> Result<()> {
+        UpdateManualPrice::handler(ctx, price, conf)
+    }
+
+    /// Value the bond holdings at the oracle price and store the reserve NAV
+    pub fn refresh_reserve_nav(ctx: Context<RefreshReserveNav>) -> Result<()> {
+        RefreshReserveNav::handler(ctx)
+    }
+
+    /// Read-only proof of reserves, returned as a borsh `ReserveStatus` via return data
+    pub fn get_reserve_status(ctx: Context<GetReserveStatus>) -> Result<ReserveStatus> {
+        GetReserveStatus::handler(ctx)
+    }
+
+    /// Reconcile cached reserve amounts with the actual token balances
+    pub fn sync_reserves(ctx: Context<SyncReserves>) -> Result<()> {
+        SyncReserves::handler(ctx)
+    }
+
+    /// Set an interest-bearing coin's rate from the holder share of accrued yield
+    pub fn harvest_yield(ctx: Context<HarvestYield>) -> Result<()> {
+        HarvestYield::handler(ctx)
+    }
+
+    /// Treasurer withdrawal of yield set aside by sync_reserves
+    pub fn withdraw_accrued_yield(ctx: Context<WithdrawAccruedYield>, fiat_amount: u64, bond_amount: u64) -> Result<()> {
+        WithdrawAccruedYield::handler(ctx, fiat_amount, bond_amount)
+    }
+
+    /// Freeze a holder's token account, with the mint's freeze authority held by the coin PDA
+    pub fn freeze_holder(ctx: Context<FreezeHolder>, reason: String) -> Result<()> {
+        FreezeHolder::handler(ctx, reason)
+    }
+
+    pub fn thaw_holder(ctx: Context<ThawHolder>, reason: String) -> Result<()> {
+        ThawHolder::handler(ctx, reason)
+    }
+
+    /// Court-ordered seizure through a Token-2022 permanent delegate
+    pub fn seize_holder<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeizeHolder<'info>>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        SeizeHolder::handler(ctx, amount, reason)
+    }
+
+    /// Assign a role in a factory or coin Roles account, replacing its holder
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+        GrantRole::handler(ctx, role, holder)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        RevokeRole::handler(ctx, role)
+    }
+
+    /// Queue a Factory parameter or bond mapping update behind the timelock
+    pub fn queue_factory_change(ctx: Context<QueueFactoryChange>, change: FactoryChange) -> Result<()> {
+        QueueFactoryChange::handler(ctx, change)
+    }
+
+    pub fn execute_factory_change(ctx: Context<ExecuteFactoryChange>) -> Result<()> {
+        ExecuteFactoryChange::handler(ctx)
+    }
+
+    pub fn cancel_factory_change(ctx: Context<CancelFactoryChange>) -> Result<()> {
+        CancelFactoryChange::handler(ctx)
+    }
+
+    /// Max supply and per-window mint/redeem limits, within the factory bounds
+    pub fn set_coin_limits(
+        ctx: Context<SetCoinLimits>,
+        max_supply: u64,
+        mint_limit: u64,
+        redeem_limit: u64,
+    ) -> Result<()> {
+        SetCoinLimits::handler(ctx, max_supply, mint_limit, redeem_limit)
+    }
+
+    /// Issuer fee tiers by size, charged on top of the protocol fee
+    pub fn set_coin_fees(ctx: Context<SetCoinFees>, tiers: Vec<FeeTier>) -> Result<()> {
+        SetCoinFees::handler(ctx, tiers)
+    }
+
+    /// Exempt a wallet from the coin's mint and redeem fees
+    pub fn add_market_maker(ctx: Context<AddMarketMaker>, wallet: Pubkey) -> Result<()> {
+        AddMarketMaker::handler(ctx, wallet)
+    }
+
+    pub fn remove_market_maker(ctx: Context<RemoveMarketMaker>, wallet: Pubkey) -> Result<()> {
+        RemoveMarketMaker::handler(ctx, wallet)
+    }
+
+    /// Read-only fee breakdown for a mint or redeem, returned via return data
+    pub fn quote_fees(ctx: Context<QuoteFees>, operation: FeeOperation, amount: u64) -> Result<FeeBreakdown> {
+        QuoteFees::handler(ctx, operation, amount)
+    }
+
+    /// Hand the mint authority of a coin set up by an older version from the
+    /// issuer's wallet to the coin PDA
+    pub fn delegate_mint_authority(ctx: Context<DelegateMintAuthority>) -> Result<()> {
+        DelegateMintAuthority::handler(ctx)
+    }
+
+    /// Per-coin flash mint cap and fee; a zero cap disables flash mints
+    pub fn set_flash_mint_config(ctx: Context<SetFlashMintConfig>, cap: u64, fee_bps: u16) -> Result<()> {
+        SetFlashMintConfig::handler(ctx, cap, fee_bps)
+    }
+
+    /// Mint coins that a later flash_repay in the same transaction must burn
+    pub fn flash_mint(ctx: Context<FlashMint>, amount: u64) -> Result<()> {
+        FlashMint::handler(ctx, amount)
+    }
+
+    pub fn flash_repay<'info>(ctx: Context<'_, '_, 'info, 'info, FlashRepay<'info>>) -> Result<()> {
+        FlashRepay::handler(ctx)
+    }
+
+    /// Create the rate account for a currency pair, price in quote units per base unit
+    pub fn init_fx_rate(
+        ctx: Context<InitFxRate>,
+        base_currency: String,
+        quote_currency: String,
+        expo: i32,
+        max_price_age: u32,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        InitFxRate::handler(ctx, base_currency, quote_currency, expo, max_price_age, max_confidence_bps)
+    }
+
+    pub fn update_fx_rate(ctx: Context<UpdateFxRate>, price: i64, conf: u64) -> Result<()> {
+        UpdateFxRate::handler(ctx, price, conf)
+    }
+
+    /// Treasurer withdrawal of liquidity from the FX pool
+    pub fn withdraw_fx_pool(ctx: Context<WithdrawFxPool>, amount: u64) -> Result<()> {
+        WithdrawFxPool::handler(ctx, amount)
+    }
+
+    /// Burn one coin and mint another at the FX rate, settling reserves through the FX pool
+    pub fn swap_sovereign_coins(ctx: Context<SwapSovereignCoins>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        SwapSovereignCoins::handler(ctx, amount_in, min_amount_out)
+    }
+
+    /// Redeem coins for fiat at par, queueing behind a RedemptionTicket if the reserve cannot pay now
+    pub fn redeem_sovereign_coin<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemSovereignCoin<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        RedeemSovereignCoin::handler(ctx, amount)
+    }
+
+    /// Escrow coins behind a RedemptionTicket at the back of the coin's queue
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestRedemption<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        RequestRedemption::handler(ctx, amount)
+    }
+
+    pub fn cancel_redemption<'info>(ctx: Context<'_, '_, 'info, 'info, CancelRedemption<'info>>) -> Result<()> {
+        CancelRedemption::handler(ctx)
+    }
+
+    /// Crank: settle the ticket at the front of the queue from the fiat reserve
+    pub fn settle_redemption(ctx: Context<SettleRedemption>) -> Result<()> {
+        SettleRedemption::handler(ctx)
+    }
+
+    /// Crank: move a coin's matured bond position into the current issue
+    pub fn rollover_bonds(ctx: Context<RolloverBonds>) -> Result<()> {
+        RolloverBonds::handler(ctx)
+    }
+
+    /// Crank: trade fiat and bonds back inside the rebalance band, for a keeper reward
+    pub fn rebalance_reserves(ctx: Context<RebalanceReserves>) -> Result<()> {
+        RebalanceReserves::handler(ctx)
+    }
+
+    pub fn init_insurance_vault(ctx: Context<InitInsuranceVault>, fiat_currency: String) -> Result<()> {
+        InitInsuranceVault::handler(ctx, fiat_currency)
+    }
+
+    /// Move fiat from the currency's insurance vault into a coin's fiat reserve, up to its shortfall
+    pub fn cover_shortfall(ctx: Context<CoverShortfall>, amount: u64) -> Result<()> {
+        CoverShortfall::handler(ctx, amount)
+    }
+
+    /// Register the signer as a referrer for a coin's primary mints
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        RegisterReferrer::handler(ctx)
+    }
+
+    /// Mint coins against fiat deposited into the reserve, optionally crediting a referrer
+    pub fn mint_sovereign_coin(ctx: Context<MintSovereignCoin>, amount: u64) -> Result<()> {
+        MintSovereignCoin::handler(ctx, amount)
+    }
+
+    pub fn claim_referral_fees<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimReferralFees<'info>>) -> Result<()> {
+        ClaimReferralFees::handler(ctx)
+    }
+
+    /// Open an issuer profile for review by the factory
+    pub fn register_issuer(ctx: Context<RegisterIssuer>, jurisdiction: String) -> Result<()> {
+        RegisterIssuer::handler(ctx, jurisdiction)
+    }
+
+    pub fn approve_issuer(ctx: Context<ApproveIssuer>, coin_quota: u32) -> Result<()> {
+        ApproveIssuer::handler(ctx, coin_quota)
+    }
+
+    pub fn revoke_issuer(ctx: Context<RevokeIssuer>) -> Result<()> {
+        RevokeIssuer::handler(ctx)
+    }
+
+    /// Admit a wallet to a coin's direct mints and redemptions
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, wallet: Pubkey) -> Result<()> {
+        AddToAllowlist::handler(ctx, wallet)
+    }
+
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>, wallet: Pubkey) -> Result<()> {
+        RemoveFromAllowlist::handler(ctx, wallet)
+    }
+
+    /// Open a coin's primary market to everyone, or restrict it to the allowlist
+    pub fn set_permissionless(ctx: Context<SetPermissionless>, permissionless: bool) -> Result<()> {
+        SetPermissionless::handler(ctx, permissionless)
+    }
+
+    /// Upgrade the factory account in place to the current layout
+    pub fn migrate_factory(ctx: Context<MigrateFactory>) -> Result<()> {
+        MigrateFactory::handler(ctx)
+    }
+
+    /// Upgrade a sovereign coin account in place to the current layout
+    pub fn migrate_sovereign_coin(ctx: Context<MigrateSovereignCoin>) -> Result<()> {
+        MigrateSovereignCoin::handler(ctx)
+    }
+
+    /// Guardian-only emergency pause of the given PAUSE_* flags for every coin.
+    /// PAUSE_TRANSFER only stops coins that have the compliance hook.
+    pub fn pause_factory(ctx: Context<PauseFactory>, flags: u8) -> Result<()> {
+        PauseFactory::handler(ctx, flags)
+    }
+
+    pub fn unpause_factory(ctx: Context<UnpauseFactory>, flags: u8) -> Result<()> {
+        UnpauseFactory::handler(ctx, flags)
+    }
+
+    /// Guardian-only: lift a tripped circuit breaker and clear the pause it applied
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        ResetCircuitBreaker::handler(ctx)
+    }
+
+    /// Guardian-only emergency pause of the given PAUSE_* flags for one coin
+    pub fn pause_sovereign_coin(ctx: Context<PauseSovereignCoin>, flags: u8) -> Result<()> {
+        PauseSovereignCoin::handler(ctx, flags)
+    }
+
+    pub fn unpause_sovereign_coin(ctx: Context<UnpauseSovereignCoin>, flags: u8) -> Result<()> {
+        UnpauseSovereignCoin::handler(ctx, flags)
+    }
+
+    /// Freeze minting and snapshot the reserves for pro-rata settlement
+    pub fn begin_wind_down(ctx: Context<BeginWindDown>) -> Result<()> {
+        BeginWindDown::handler(ctx)
+    }
+
+    /// Burn coins for a pro-rata share of the snapshotted fiat and bond reserves
+    pub fn settle_redeem(ctx: Context<SettleRedeem>, amount: u64) -> Result<()> {
+        SettleRedeem::handler(ctx, amount)
+    }
+
+    /// Reclaim rent once a wound-down coin has no supply left
+    pub fn close_sovereign_coin(ctx: Context<CloseSovereignCoin>) -> Result<()> {
+        CloseSovereignCoin::handler(ctx)
+    }
 }
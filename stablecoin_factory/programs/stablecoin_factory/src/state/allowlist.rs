@@ -0,0 +1,13 @@
+use super::*;
+
+
+/// Admits a wallet to one coin's primary market, direct mints and redemptions,
+/// while the coin is not permissionless.
+#[account]
+#[derive(InitSpace)]
+pub struct PrimaryMarketAllowlist {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub wallet: Pubkey,
+    pub added_at: i64,
+}
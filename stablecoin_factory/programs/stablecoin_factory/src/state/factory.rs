@@ -1,25 +1,173 @@
 use super::*;
 
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum OracleSource {
+    None,                            // No price feed configured, NAV cannot be computed
+    Pyth,                            // Pyth-style price account
+    Manual,                          // Program-owned ManualPrice account written by the authority
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SurplusPolicy {
+    ProtocolOwned,                   // Surplus is folded into the coin's reserves
+    Yield,                           // Surplus is set aside as distributable yield
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BreakerTrip {
+    None,                            // Not tripped
+    Outflow,                         // Redemptions in one currency exceeded outflow_limit
+    PriceDrop {
+        fiat_currency: [u8; 8],      // Mapping whose bond price fell
+        drop_bps: u16,               // Fall from the window's reference price
+    },
+}
+
+impl OracleSource {
+    /// Decodes the u8 stored in a BondCurrencyMapping.
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => OracleSource::Pyth,
+            2 => OracleSource::Manual,
+            _ => OracleSource::None,
+        }
+    }
+}
+
+impl SurplusPolicy {
+    /// Decodes the u8 stored in the Factory.
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => SurplusPolicy::Yield,
+            _ => SurplusPolicy::ProtocolOwned,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RebalanceDirection {
+    BuyBonds,                        // Fiat buffer was above the band
+    SellBonds,                       // Fiat buffer was below the band
+}
+
+/// Bond registered for one fiat currency. Zero-copy, so flags and enums are
+/// stored as u8 and the padding is spelled out.
+#[zero_copy]
 pub struct BondCurrencyMapping {
-    pub active: bool,
-    pub fiat_currency: [u8; 8],      // Currency code (e.g., "USD", "MXN")
     pub bond_mint: Pubkey,           // The Stablebond token mint
+
+    // Bond price feed
+    pub price_oracle: Pubkey,        // Price account for the bond, in fiat terms
+
+    // Coins on each mint. A timelocked mint change moves the old one aside
+    // until the rollover crank has moved its coins onto bond_mint
+    pub coin_count: u64,             // Coins holding bond_mint
+    pub previous_bond_mint: Pubkey,  // Mint the mapping last moved off, default if never
+    pub previous_coin_count: u64,    // Coins still holding previous_bond_mint
+
+    // Circuit breaker reference, the highest price seen in the current window
+    pub reference_price: i64,
+    pub reference_window_start: i64, // 0 until the first price is observed
+
+    // Circuit breaker outflow window, a RateLimit against the factory's outflow_limit
+    pub outflow_window_start: i64,
+    pub outflow_used: u64,              // Coins redeemed in this currency in the window
+
+    pub reference_expo: i32,
+
+    pub max_price_age: u32,          // Seconds before a price is considered stale
+    pub max_confidence_bps: u16,     // Max confidence interval relative to price
+    pub fiat_currency: [u8; 8],      // Currency code (e.g., "USD", "MXN")
+    pub active: u8,                  // 1 while coins may be created against it
     pub bond_rating: u8,             // Bond rating (1-10)
+    pub oracle_source: u8,           // OracleSource, which price account layout to read
+    pub _padding: [u8; 3],
+}
+
+impl BondCurrencyMapping {
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+
+    pub fn oracle_source(&self) -> OracleSource {
+        OracleSource::from_u8(self.oracle_source)
+    }
+
+    pub fn matches_currency(&self, fiat_currency: &[u8]) -> bool {
+        let mapping_len = self.fiat_currency.iter().take_while(|&&b| b != 0).count();
+        self.is_active() && self.fiat_currency[..mapping_len] == *fiat_currency
+    }
+
+    /// Returns the coin count for whichever of the mapping's mints `bond_mint`
+    /// is, if either.
+    pub fn coin_count_mut(&mut self, bond_mint: &Pubkey) -> Option<&mut u64> {
+        if *bond_mint == self.bond_mint {
+            Some(&mut self.coin_count)
+        } else if *bond_mint == self.previous_bond_mint {
+            Some(&mut self.previous_coin_count)
+        } else {
+            None
+        }
+    }
+
+    /// Forgets the circuit breaker reference, so the next observed price starts a new window.
+    pub fn reset_reference_price(&mut self) {
+        self.reference_price = 0;
+        self.reference_expo = 0;
+        self.reference_window_start = 0;
+    }
 }
 
 
-#[account]
-#[derive(InitSpace)]
+/// Global configuration, read by nearly every instruction. Zero-copy so it is
+/// not deserialized each time: fields are grouped by size with explicit
+/// padding, and flags and enums are stored as u8.
+#[account(zero_copy)]
 pub struct Factory {
-    pub bump: u8,
-    pub authority: Pubkey,  // Admin who can update certain parameters
+    pub authority: Pubkey,  // Creator; admin rights live in the factory Roles account
     pub treasury: Pubkey,   // Treasury account to collect fees if any
     
     // Tracking metrics
     pub total_sovereign_coins: u64,  // Count of all sovereign coins created
-    pub total_supply_all_coins: u128, // Combined market cap of all coins
+    pub total_supply_all_coins: [u8; 16], // Combined market cap of all coins, a little-endian u128
+
+    // Bounds for per-coin issuance limits
+    pub coin_max_supply_cap: u64,       // Highest max_supply a coin may set
+    pub coin_mint_limit_cap: u64,       // Highest mint limit per window
+    pub coin_redeem_limit_cap: u64,     // Highest redeem limit per window
+
+    // Governance timelock
+    pub timelock_delay: i64,            // Seconds a PendingChange waits before execution
+    pub next_change_id: u64,            // Id of the next PendingChange
+
+    // Circuit breaker, each mapping keeps its own outflow window
+    pub outflow_limit: u64,             // Coins redeemed per currency per window
+    pub breaker_tripped_at: i64,
+
+    // Issuer onboarding
+    pub coin_creation_fee: u64,         // Lamports paid to the treasury for each new coin
+
+    // Protocol fees
+    pub mint_fee_bps: u16,              // Fee in basis points for minting, if any
+    pub burn_fee_bps: u16,              // Fee in basis points for burning, if any
+    pub max_issuer_fee_bps: u16,        // Highest issuer fee a coin may charge on top
+    pub swap_fee_bps: u16,              // Fee on cross-currency swaps, kept by the FX pool
+    pub referral_share_bps: u16,        // Of the mint protocol fee, accrued to the referrer
+
+    // Circuit breaker
+    pub max_price_drop_bps: u16,        // Bond price fall within a window that trips, 0 disables
+    pub breaker_trip_drop_bps: u16,     // Price fall that tripped it, for BreakerTrip::PriceDrop
+
+    // Reserve rebalancing
+    pub rebalance_band_bps: u16,        // Allowed fiat drift from the requirement, of supply; 0 disables
+    pub keeper_reward_bps: u16,         // Of the fiat moved, paid to the keeper from accrued yield
+
+    // Insurance
+    pub insurance_fee_share_bps: u16,   // Of fiat protocol fees, sent to the currency's insurance vault
+
+    pub bump: u8,
+    pub version: u8,                    // Layout version, FACTORY_VERSION
 
     // Bond rating configuration
     pub bond_rating_ordinals: [u8; 10],  // AAA=1, AA=2, etc.
@@ -32,12 +180,170 @@ pub struct Factory {
     pub yield_share_protocol: u8,        // Percentage of yield for protocol
     pub yield_share_issuer: u8,          // Percentage for coin issuers
     pub yield_share_holders: u8,         // Percentage for coin holders (stakers)
-    
-    // Protocol fees
-    pub mint_fee_bps: u16,              // Fee in basis points for minting, if any
-    pub burn_fee_bps: u16,              // Fee in basis points for burning, if any
+
+    // Reserve reconciliation
+    pub surplus_policy: u8,             // SurplusPolicy, how sync_reserves treats unaccounted balances
+
+    // Emergency controls
+    pub paused: u8,                     // PAUSE_* flags applied to every coin
+
+    // Circuit breaker
+    pub breaker_flags: u8,              // PAUSE_* flags a trip applies
+    pub breaker_trip_kind: u8,          // 0 none, 1 outflow, 2 price drop; see breaker_trip()
+    pub breaker_paused: u8,             // Flags the trip set that were not already paused
+    pub breaker_trip_currency: [u8; 8], // Mapping whose price fell, for BreakerTrip::PriceDrop
 
     // Bond mapping
     pub bond_mappings_count: u8,
+    pub _padding: [u8; 5],
     pub bond_mappings: [BondCurrencyMapping; MAX_BOND_MAPPINGS],
+
+    // New fields come out of reserved
+    pub reserved: [u8; FACTORY_RESERVED_BYTES],
+}
+
+impl Factory {
+    pub const LEN: usize = 8 + std::mem::size_of::<Factory>();
+
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.paused & flags != 0
+    }
+
+    pub fn surplus_policy(&self) -> SurplusPolicy {
+        SurplusPolicy::from_u8(self.surplus_policy)
+    }
+
+    pub fn breaker_trip(&self) -> BreakerTrip {
+        match self.breaker_trip_kind {
+            1 => BreakerTrip::Outflow,
+            2 => BreakerTrip::PriceDrop {
+                fiat_currency: self.breaker_trip_currency,
+                drop_bps: self.breaker_trip_drop_bps,
+            },
+            _ => BreakerTrip::None,
+        }
+    }
+
+    pub fn set_breaker_trip(&mut self, trip: BreakerTrip) {
+        (self.breaker_trip_kind, self.breaker_trip_currency, self.breaker_trip_drop_bps) = match trip {
+            BreakerTrip::None => (0, [0u8; 8], 0),
+            BreakerTrip::Outflow => (1, [0u8; 8], 0),
+            BreakerTrip::PriceDrop { fiat_currency, drop_bps } => (2, fiat_currency, drop_bps),
+        };
+    }
+
+    pub fn is_breaker_tripped(&self) -> bool {
+        self.breaker_trip_kind != 0
+    }
+
+    /// Counts `amount` of redeemed coins against the outflow window of their
+    /// currency, given the zero-padded code, and trips the breaker once the
+    /// window's limit is exceeded. Currencies are kept apart so amounts in
+    /// different units are never summed. The outflow that crosses the limit
+    /// still goes through. Returns true if this call tripped it.
+    /// Call from every path that pays out reserves for burned coins.
+    pub fn record_outflow(&mut self, fiat_currency: &[u8; 8], amount: u64, now: i64) -> bool {
+        let outflow_limit = self.outflow_limit;
+        let Some(mapping) = self.find_registered_mapping_mut(fiat_currency) else {
+            return false;
+        };
+        let mut window = RateLimit {
+            limit: outflow_limit,
+            window_start: mapping.outflow_window_start,
+            used: mapping.outflow_used,
+        };
+        let consumed = window.try_consume(amount, now);
+        mapping.outflow_window_start = window.window_start;
+        mapping.outflow_used = window.used;
+        if consumed {
+            return false;
+        }
+        self.trip_breaker(BreakerTrip::Outflow, now)
+    }
+
+    /// Tracks the highest bond price for a currency within the current window and
+    /// trips the breaker if `price` has fallen more than max_price_drop_bps below
+    /// it. Returns true if this call tripped it.
+    pub fn observe_bond_price(&mut self, fiat_currency: &[u8], price: i64, expo: i32, now: i64) -> bool {
+        let max_price_drop_bps = self.max_price_drop_bps;
+        let Some(mapping) = self.find_bond_mapping_mut(fiat_currency) else {
+            return false;
+        };
+
+        let window_start = now - now.rem_euclid(RATE_LIMIT_WINDOW);
+        if mapping.reference_window_start != window_start || mapping.reference_expo != expo {
+            // First price of a new window
+            mapping.reference_window_start = window_start;
+            mapping.reference_expo = expo;
+            mapping.reference_price = price;
+            return false;
+        }
+        if price >= mapping.reference_price {
+            mapping.reference_price = price;
+            return false;
+        }
+
+        let drop_bps = u16::try_from(
+            (mapping.reference_price - price) as i128 * 10_000 / mapping.reference_price as i128,
+        )
+        .unwrap_or(u16::MAX);
+        if max_price_drop_bps == 0 || drop_bps <= max_price_drop_bps {
+            return false;
+        }
+        let fiat_currency = mapping.fiat_currency;
+        self.trip_breaker(BreakerTrip::PriceDrop { fiat_currency, drop_bps }, now)
+    }
+
+    /// Applies breaker_flags and records why. A breaker that is already tripped
+    /// keeps its original reason.
+    fn trip_breaker(&mut self, trip: BreakerTrip, now: i64) -> bool {
+        if self.is_breaker_tripped() {
+            return false;
+        }
+        self.set_breaker_trip(trip);
+        self.breaker_tripped_at = now;
+        self.breaker_paused = self.breaker_flags & !self.paused;
+        self.paused |= self.breaker_flags;
+        true
+    }
+
+    /// Lifts the flags the trip applied and restarts the outflow windows and
+    /// price references, so the same readings do not trip it again at once.
+    pub fn reset_breaker(&mut self) {
+        self.paused &= !self.breaker_paused;
+        self.set_breaker_trip(BreakerTrip::None);
+        self.breaker_tripped_at = 0;
+        self.breaker_paused = 0;
+        for mapping in self.bond_mappings[..self.bond_mappings_count as usize].iter_mut() {
+            mapping.outflow_used = 0;
+            mapping.reset_reference_price();
+        }
+    }
+
+    /// Returns the active bond mapping registered for a fiat currency code.
+    pub fn find_bond_mapping(&self, fiat_currency: &[u8]) -> Option<&BondCurrencyMapping> {
+        self.bond_mappings[..self.bond_mappings_count as usize]
+            .iter()
+            .find(|mapping| mapping.matches_currency(fiat_currency))
+    }
+
+    /// Returns the bond mapping registered for a fiat currency, active or not,
+    /// given the zero-padded code.
+    pub fn find_registered_mapping(&self, fiat_currency: &[u8; 8]) -> Option<&BondCurrencyMapping> {
+        self.bond_mappings[..self.bond_mappings_count as usize]
+            .iter()
+            .find(|mapping| mapping.fiat_currency == *fiat_currency)
+    }
+
+    pub fn find_registered_mapping_mut(&mut self, fiat_currency: &[u8; 8]) -> Option<&mut BondCurrencyMapping> {
+        self.bond_mappings[..self.bond_mappings_count as usize]
+            .iter_mut()
+            .find(|mapping| mapping.fiat_currency == *fiat_currency)
+    }
+
+    pub fn find_bond_mapping_mut(&mut self, fiat_currency: &[u8]) -> Option<&mut BondCurrencyMapping> {
+        self.bond_mappings[..self.bond_mappings_count as usize]
+            .iter_mut()
+            .find(|mapping| mapping.matches_currency(fiat_currency))
+    }
 }
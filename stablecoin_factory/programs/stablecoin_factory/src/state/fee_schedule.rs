@@ -0,0 +1,71 @@
+use super::*;
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum FeeOperation {
+    Mint,
+    Redeem,
+}
+
+/// Issuer fee applied to operations of at least `min_amount` coin base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct FeeTier {
+    pub min_amount: u64,
+    pub mint_fee_bps: u16,
+    pub redeem_fee_bps: u16,
+}
+
+/// Issuer-set fees charged on top of the factory's protocol fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct FeeSchedule {
+    pub tiers_count: u8,
+    pub tiers: [FeeTier; MAX_FEE_TIERS], // Ascending by min_amount, the first starting at 0
+}
+
+impl FeeSchedule {
+    /// Checks `tiers` are ordered, start at zero and stay within `max_fee_bps`.
+    pub fn validate(tiers: &[FeeTier], max_fee_bps: u16) -> Result<()> {
+        require!(tiers.len() <= MAX_FEE_TIERS, StablecoinError::InvalidFeeTiers);
+        if let Some(first) = tiers.first() {
+            require!(first.min_amount == 0, StablecoinError::InvalidFeeTiers);
+        }
+        require!(
+            tiers.windows(2).all(|pair| pair[0].min_amount < pair[1].min_amount),
+            StablecoinError::InvalidFeeTiers
+        );
+        require!(
+            tiers.iter().all(|tier| tier.mint_fee_bps <= max_fee_bps && tier.redeem_fee_bps <= max_fee_bps),
+            StablecoinError::FeeAboveFactoryBound
+        );
+        Ok(())
+    }
+
+    pub fn new(tiers: &[FeeTier]) -> Self {
+        let mut schedule = Self { tiers_count: tiers.len() as u8, ..Default::default() };
+        schedule.tiers[..tiers.len()].copy_from_slice(tiers);
+        schedule
+    }
+
+    /// Issuer fee for an operation of `amount`, from the highest tier it reaches.
+    /// Zero with no tiers.
+    pub fn fee_bps(&self, operation: FeeOperation, amount: u64) -> u16 {
+        self.tiers[..self.tiers_count as usize]
+            .iter()
+            .rev()
+            .find(|tier| amount >= tier.min_amount)
+            .map_or(0, |tier| match operation {
+                FeeOperation::Mint => tier.mint_fee_bps,
+                FeeOperation::Redeem => tier.redeem_fee_bps,
+            })
+    }
+}
+
+/// Marks a wallet as a market maker for one coin, exempt from its protocol and issuer fees.
+#[account]
+#[derive(InitSpace)]
+pub struct MarketMaker {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub wallet: Pubkey,
+    pub added_at: i64,
+}
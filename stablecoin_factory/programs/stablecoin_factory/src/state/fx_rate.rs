@@ -0,0 +1,34 @@
+use super::*;
+
+
+/// Exchange rate between two fiat currencies, pushed by the rate oracle updater
+/// and used to swap one sovereign coin for another.
+#[account]
+#[derive(InitSpace)]
+pub struct FxRate {
+    pub bump: u8,
+    pub base_currency: [u8; 8],  // Currency being priced
+    pub quote_currency: [u8; 8], // Currency the price is expressed in
+    
+    // Same semantics as ManualPrice: quote units per base unit = price * 10^expo
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+    
+    // Acceptance bounds, as on a bond mapping
+    pub max_price_age: u32,
+    pub max_confidence_bps: u16,
+}
+
+impl FxRate {
+    pub fn base_code(&self) -> &[u8] {
+        let len = self.base_currency.iter().take_while(|&&b| b != 0).count();
+        &self.base_currency[..len]
+    }
+
+    pub fn quote_code(&self) -> &[u8] {
+        let len = self.quote_currency.iter().take_while(|&&b| b != 0).count();
+        &self.quote_currency[..len]
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum KycStatus {
+    Pending,                   // Registered, awaiting review by the factory
+    Approved,                  // Vetted; may create up to coin_quota coins
+}
+
+/// Onboarding record for one issuer. Coins can only be created by an issuer
+/// the factory has approved and not since revoked.
+#[account]
+#[derive(InitSpace)]
+pub struct IssuerProfile {
+    pub bump: u8,
+    pub issuer: Pubkey,        // Authority of the coins this profile covers
+    pub kyc_status: KycStatus,
+    pub jurisdiction: [u8; 8], // Country code, e.g. "US", "MX"
+    pub coin_quota: u32,       // Most coins the issuer may create
+    pub coins_created: u32,    // Coins created so far, closed ones included
+    pub revoked: bool,         // Blocks new coins; existing coins are unaffected
+    pub registered_at: i64,
+    pub approved_at: i64,      // 0 until approved
+}
+
+impl IssuerProfile {
+    /// Fails unless the issuer may create one more coin.
+    pub fn require_can_create(&self) -> Result<()> {
+        require!(self.kyc_status == KycStatus::Approved, StablecoinError::IssuerNotApproved);
+        require!(!self.revoked, StablecoinError::IssuerRevoked);
+        require!(self.coins_created < self.coin_quota, StablecoinError::IssuerQuotaExceeded);
+        Ok(())
+    }
+}
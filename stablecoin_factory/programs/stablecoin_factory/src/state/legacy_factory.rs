@@ -0,0 +1,123 @@
+use super::*;
+
+/// BondCurrencyMapping as Borsh-encoded by v1 and v2 factories.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyBondCurrencyMapping {
+    pub active: bool,
+    pub fiat_currency: [u8; 8],
+    pub bond_mint: Pubkey,
+    pub bond_rating: u8,
+    pub oracle_source: OracleSource,
+    pub price_oracle: Pubkey,
+    pub max_price_age: u32,
+    pub max_confidence_bps: u16,
+    pub reference_price: i64,
+    pub reference_expo: i32,
+    pub reference_window_start: i64,
+}
+
+/// Factory as Borsh-encoded by v1 and v2 programs, before it became zero-copy.
+/// Only read by migrate_factory. v2 appended version and reserved, which are
+/// left unread.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyFactory {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub total_sovereign_coins: u64,
+    pub total_supply_all_coins: u128,
+    pub bond_rating_ordinals: [u8; 10],
+    pub min_fiat_reserve_percentage: u8,
+    pub bond_reserve_multiplier: u8,
+    pub yield_share_protocol: u8,
+    pub yield_share_issuer: u8,
+    pub yield_share_holders: u8,
+    pub mint_fee_bps: u16,
+    pub burn_fee_bps: u16,
+    pub max_issuer_fee_bps: u16,
+    pub swap_fee_bps: u16,
+    pub referral_share_bps: u16,
+    pub surplus_policy: SurplusPolicy,
+    pub paused: u8,
+    pub coin_max_supply_cap: u64,
+    pub coin_mint_limit_cap: u64,
+    pub coin_redeem_limit_cap: u64,
+    pub timelock_delay: i64,
+    pub next_change_id: u64,
+    pub outflow_limit: RateLimit,
+    pub max_price_drop_bps: u16,
+    pub breaker_flags: u8,
+    pub breaker_trip: BreakerTrip,
+    pub breaker_tripped_at: i64,
+    pub breaker_paused: u8,
+    pub rebalance_band_bps: u16,
+    pub keeper_reward_bps: u16,
+    pub insurance_fee_share_bps: u16,
+    pub coin_creation_fee: u64,
+    pub bond_mappings_count: u8,
+    pub bond_mappings: [LegacyBondCurrencyMapping; MAX_BOND_MAPPINGS],
+}
+
+impl LegacyFactory {
+    /// Account size, discriminator included, of a v1 factory.
+    pub const V1_LEN: usize = 8 + Self::INIT_SPACE;
+    /// Account size of a v2 factory, which appended version and reserved.
+    pub const V2_LEN: usize = Self::V1_LEN + 1 + FACTORY_RESERVED_BYTES;
+
+    /// Copies every field into a zeroed zero-copy factory. A timelock delay
+    /// below the minimum, which older layouts allowed, is raised to it. The
+    /// single outflow window is carried into every mapping, as it cannot be
+    /// split by currency.
+    pub fn migrate(self, factory: &mut Factory) {
+        factory.bump = self.bump;
+        factory.authority = self.authority;
+        factory.treasury = self.treasury;
+        factory.total_sovereign_coins = self.total_sovereign_coins;
+        factory.total_supply_all_coins = self.total_supply_all_coins.to_le_bytes();
+        factory.bond_rating_ordinals = self.bond_rating_ordinals;
+        factory.min_fiat_reserve_percentage = self.min_fiat_reserve_percentage;
+        factory.bond_reserve_multiplier = self.bond_reserve_multiplier;
+        factory.yield_share_protocol = self.yield_share_protocol;
+        factory.yield_share_issuer = self.yield_share_issuer;
+        factory.yield_share_holders = self.yield_share_holders;
+        factory.mint_fee_bps = self.mint_fee_bps;
+        factory.burn_fee_bps = self.burn_fee_bps;
+        factory.max_issuer_fee_bps = self.max_issuer_fee_bps;
+        factory.swap_fee_bps = self.swap_fee_bps;
+        factory.referral_share_bps = self.referral_share_bps;
+        factory.surplus_policy = self.surplus_policy as u8;
+        factory.paused = self.paused;
+        factory.coin_max_supply_cap = self.coin_max_supply_cap;
+        factory.coin_mint_limit_cap = self.coin_mint_limit_cap;
+        factory.coin_redeem_limit_cap = self.coin_redeem_limit_cap;
+        factory.timelock_delay = self.timelock_delay.max(MIN_TIMELOCK_DELAY);
+        factory.next_change_id = self.next_change_id;
+        factory.outflow_limit = self.outflow_limit.limit;
+        factory.max_price_drop_bps = self.max_price_drop_bps;
+        factory.breaker_flags = self.breaker_flags;
+        factory.set_breaker_trip(self.breaker_trip);
+        factory.breaker_tripped_at = self.breaker_tripped_at;
+        factory.breaker_paused = self.breaker_paused;
+        factory.rebalance_band_bps = self.rebalance_band_bps;
+        factory.keeper_reward_bps = self.keeper_reward_bps;
+        factory.insurance_fee_share_bps = self.insurance_fee_share_bps;
+        factory.coin_creation_fee = self.coin_creation_fee;
+        factory.bond_mappings_count = self.bond_mappings_count;
+        for (mapping, legacy) in factory.bond_mappings.iter_mut().zip(self.bond_mappings) {
+            mapping.bond_mint = legacy.bond_mint;
+            mapping.price_oracle = legacy.price_oracle;
+            mapping.reference_price = legacy.reference_price;
+            mapping.reference_window_start = legacy.reference_window_start;
+            mapping.reference_expo = legacy.reference_expo;
+            mapping.outflow_window_start = self.outflow_limit.window_start;
+            mapping.outflow_used = self.outflow_limit.used;
+            mapping.max_price_age = legacy.max_price_age;
+            mapping.max_confidence_bps = legacy.max_confidence_bps;
+            mapping.fiat_currency = legacy.fiat_currency;
+            mapping.active = legacy.active as u8;
+            mapping.bond_rating = legacy.bond_rating;
+            mapping.oracle_source = legacy.oracle_source as u8;
+        }
+        factory.version = FACTORY_VERSION;
+    }
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+
+/// Price account written directly by the factory authority. Used where no
+/// external feed exists for a bond, and as a local stand-in for Pyth in tests.
+#[account]
+#[derive(InitSpace)]
+pub struct ManualPrice {
+    pub bump: u8,
+    pub bond_mint: Pubkey,     // The Stablebond token mint this price is for
+    
+    // Same semantics as a Pyth aggregate price: value = price * 10^expo
+    pub price: i64,            // Price of one whole bond token in fiat units
+    pub conf: u64,             // Confidence interval, same exponent as price
+    pub expo: i32,             // Price exponent (e.g. -6)
+    pub publish_time: i64,     // Unix timestamp of the last update
+}
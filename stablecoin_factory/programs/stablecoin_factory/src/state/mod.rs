@@ -1,7 +1,27 @@
 use super::*;
 
+pub mod allowlist;
 pub mod factory;
+pub mod fee_schedule;
+pub mod fx_rate;
+pub mod issuer_profile;
+pub mod legacy_factory;
+pub mod manual_price;
+pub mod pending_change;
+pub mod redemption_ticket;
+pub mod referrer;
+pub mod roles;
 pub mod stablecoin;
 
+pub use allowlist::*;
 pub use factory::*;
-pub use stablecoin::*;
\ No newline at end of file
+pub use fee_schedule::*;
+pub use fx_rate::*;
+pub use issuer_profile::*;
+pub use legacy_factory::*;
+pub use manual_price::*;
+pub use pending_change::*;
+pub use redemption_ticket::*;
+pub use referrer::*;
+pub use roles::*;
+pub use stablecoin::*;
@@ -0,0 +1,264 @@
+use super::*;
+
+
+/// Factory update that only takes effect through the timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum FactoryChange {
+    ReserveParameters {
+        min_fiat_reserve_percentage: u8,
+        bond_reserve_multiplier: u8,
+    },
+    YieldShares {
+        protocol: u8,
+        issuer: u8,
+        holders: u8,
+    },
+    Fees {
+        mint_fee_bps: u16,
+        burn_fee_bps: u16,
+    },
+    IssuerFeeBound {
+        max_issuer_fee_bps: u16,
+    },
+    SwapFee {
+        swap_fee_bps: u16,
+    },
+    Treasury {
+        treasury: Pubkey,
+    },
+    BondMapping {
+        fiat_currency: [u8; 8],      // Must match an already registered mapping
+        bond_mint: Pubkey,
+        bond_rating: u8,
+        active: bool,
+    },
+    TimelockDelay {
+        delay: i64,                  // Seconds between queue and earliest execution
+    },
+    CoinLimitBounds {
+        max_supply: u64,
+        mint_limit: u64,
+        redeem_limit: u64,
+    },
+    CircuitBreaker {
+        outflow_limit: u64,          // Coins redeemed per currency per window
+        max_price_drop_bps: u16,     // 0 disables the price check
+        breaker_flags: u8,           // PAUSE_MINT for redeem-only, PAUSE_ALL for a full pause
+    },
+    Rebalancing {
+        rebalance_band_bps: u16,     // 0 disables rebalance_reserves
+        keeper_reward_bps: u16,
+    },
+    InsuranceShare {
+        insurance_fee_share_bps: u16,
+    },
+    ReferralShare {
+        referral_share_bps: u16,     // Of the mint protocol fee
+    },
+    CreationFee {
+        coin_creation_fee: u64,      // Lamports
+    },
+    BondOracle {
+        fiat_currency: [u8; 8],      // Must match an already registered mapping
+        oracle_source: OracleSource,
+        price_oracle: Pubkey,        // For OracleSource::Manual, the bond's manual_price PDA
+        max_price_age: u32,
+        max_confidence_bps: u16,
+    },
+    SurplusPolicy {
+        surplus_policy: SurplusPolicy,
+    },
+}
+
+impl FactoryChange {
+    /// Checks the change can be applied to `factory`. Run when queued and
+    /// again when executed, since other changes may land in between.
+    pub fn validate(&self, factory: &Factory) -> Result<()> {
+        match self {
+            FactoryChange::ReserveParameters { min_fiat_reserve_percentage, .. } => {
+                require!(*min_fiat_reserve_percentage <= 100, StablecoinError::InvalidReserveParameters);
+            }
+            FactoryChange::YieldShares { protocol, issuer, holders } => {
+                require!(
+                    *protocol as u16 + *issuer as u16 + *holders as u16 == 100,
+                    StablecoinError::InvalidYieldDistribution
+                );
+            }
+            FactoryChange::Fees { mint_fee_bps, burn_fee_bps } => {
+                // Protocol and issuer fees together can never exceed the amount
+                let max_fee_bps = 10_000 - factory.max_issuer_fee_bps.min(10_000);
+                require!(
+                    *mint_fee_bps <= max_fee_bps && *burn_fee_bps <= max_fee_bps,
+                    StablecoinError::InvalidBasisPoints
+                );
+            }
+            FactoryChange::SwapFee { swap_fee_bps } => {
+                require!(*swap_fee_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+            }
+            FactoryChange::IssuerFeeBound { max_issuer_fee_bps } => {
+                require!(
+                    *max_issuer_fee_bps as u32 + factory.mint_fee_bps.max(factory.burn_fee_bps) as u32 <= 10_000,
+                    StablecoinError::InvalidBasisPoints
+                );
+            }
+            FactoryChange::Treasury { treasury } => {
+                require_keys_neq!(*treasury, Pubkey::default(), StablecoinError::InvalidTreasury);
+            }
+            FactoryChange::BondMapping { fiat_currency, bond_mint, bond_rating, .. } => {
+                require!((1..=10).contains(bond_rating), StablecoinError::InvalidBondRating);
+                let mapping = factory
+                    .find_registered_mapping(fiat_currency)
+                    .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+                // Coins on the current mint are parked on the previous one until
+                // they roll over, so the mint may only move once that slot is free
+                require!(
+                    mapping.bond_mint == *bond_mint || mapping.previous_coin_count == 0,
+                    StablecoinError::BondMintInUse
+                );
+            }
+            FactoryChange::TimelockDelay { delay } => {
+                require!(
+                    (MIN_TIMELOCK_DELAY..=MAX_TIMELOCK_DELAY).contains(delay),
+                    StablecoinError::InvalidTimelockDelay
+                );
+            }
+            FactoryChange::CoinLimitBounds { .. } => {}
+            FactoryChange::CircuitBreaker { max_price_drop_bps, breaker_flags, .. } => {
+                require!(*max_price_drop_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+                require!(
+                    *breaker_flags != 0 && *breaker_flags & !PAUSE_ALL == 0,
+                    StablecoinError::InvalidPauseFlags
+                );
+            }
+            FactoryChange::Rebalancing { rebalance_band_bps, keeper_reward_bps } => {
+                require!(*rebalance_band_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+                require!(*keeper_reward_bps <= MAX_KEEPER_REWARD_BPS, StablecoinError::KeeperRewardTooHigh);
+            }
+            FactoryChange::InsuranceShare { insurance_fee_share_bps } => {
+                require!(*insurance_fee_share_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+            }
+            FactoryChange::ReferralShare { referral_share_bps } => {
+                require!(*referral_share_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+            }
+            FactoryChange::CreationFee { .. } => {}
+            FactoryChange::BondOracle { fiat_currency, oracle_source, price_oracle, max_confidence_bps, .. } => {
+                require!(*max_confidence_bps <= 10_000, StablecoinError::InvalidBasisPoints);
+                let mapping = factory
+                    .find_registered_mapping(fiat_currency)
+                    .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+                // A manual price must be the program-owned PDA for this bond
+                if *oracle_source == OracleSource::Manual {
+                    let (manual_price, _) = Pubkey::find_program_address(
+                        &[b"manual_price", mapping.bond_mint.as_ref()],
+                        &crate::ID,
+                    );
+                    require_keys_eq!(*price_oracle, manual_price, StablecoinError::InvalidOracleAccount);
+                }
+            }
+            FactoryChange::SurplusPolicy { .. } => {}
+        }
+        Ok(())
+    }
+
+    pub fn apply(&self, factory: &mut Factory) -> Result<()> {
+        self.validate(factory)?;
+        match self {
+            FactoryChange::ReserveParameters { min_fiat_reserve_percentage, bond_reserve_multiplier } => {
+                factory.min_fiat_reserve_percentage = *min_fiat_reserve_percentage;
+                factory.bond_reserve_multiplier = *bond_reserve_multiplier;
+            }
+            FactoryChange::YieldShares { protocol, issuer, holders } => {
+                factory.yield_share_protocol = *protocol;
+                factory.yield_share_issuer = *issuer;
+                factory.yield_share_holders = *holders;
+            }
+            FactoryChange::Fees { mint_fee_bps, burn_fee_bps } => {
+                factory.mint_fee_bps = *mint_fee_bps;
+                factory.burn_fee_bps = *burn_fee_bps;
+            }
+            FactoryChange::IssuerFeeBound { max_issuer_fee_bps } => {
+                factory.max_issuer_fee_bps = *max_issuer_fee_bps;
+            }
+            FactoryChange::SwapFee { swap_fee_bps } => {
+                factory.swap_fee_bps = *swap_fee_bps;
+            }
+            FactoryChange::Treasury { treasury } => {
+                factory.treasury = *treasury;
+            }
+            FactoryChange::BondMapping { fiat_currency, bond_mint, bond_rating, active } => {
+                let mapping = factory
+                    .find_registered_mapping_mut(fiat_currency)
+                    .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+                if mapping.bond_mint != *bond_mint {
+                    mapping.previous_bond_mint = mapping.bond_mint;
+                    mapping.previous_coin_count = mapping.coin_count;
+                    mapping.coin_count = 0;
+                    mapping.reset_reference_price();
+                    // A manual price follows the bond it is for
+                    if mapping.oracle_source() == OracleSource::Manual {
+                        mapping.price_oracle = Pubkey::find_program_address(
+                            &[b"manual_price", bond_mint.as_ref()],
+                            &crate::ID,
+                        ).0;
+                    }
+                }
+                mapping.bond_mint = *bond_mint;
+                mapping.bond_rating = *bond_rating;
+                mapping.active = *active as u8;
+            }
+            FactoryChange::TimelockDelay { delay } => {
+                factory.timelock_delay = *delay;
+            }
+            FactoryChange::CoinLimitBounds { max_supply, mint_limit, redeem_limit } => {
+                factory.coin_max_supply_cap = *max_supply;
+                factory.coin_mint_limit_cap = *mint_limit;
+                factory.coin_redeem_limit_cap = *redeem_limit;
+            }
+            FactoryChange::CircuitBreaker { outflow_limit, max_price_drop_bps, breaker_flags } => {
+                factory.outflow_limit = *outflow_limit;
+                factory.max_price_drop_bps = *max_price_drop_bps;
+                factory.breaker_flags = *breaker_flags;
+            }
+            FactoryChange::Rebalancing { rebalance_band_bps, keeper_reward_bps } => {
+                factory.rebalance_band_bps = *rebalance_band_bps;
+                factory.keeper_reward_bps = *keeper_reward_bps;
+            }
+            FactoryChange::InsuranceShare { insurance_fee_share_bps } => {
+                factory.insurance_fee_share_bps = *insurance_fee_share_bps;
+            }
+            FactoryChange::ReferralShare { referral_share_bps } => {
+                factory.referral_share_bps = *referral_share_bps;
+            }
+            FactoryChange::CreationFee { coin_creation_fee } => {
+                factory.coin_creation_fee = *coin_creation_fee;
+            }
+            FactoryChange::BondOracle { fiat_currency, oracle_source, price_oracle, max_price_age, max_confidence_bps } => {
+                let mapping = factory
+                    .find_registered_mapping_mut(fiat_currency)
+                    .ok_or(StablecoinError::NoBondMappingForCurrency)?;
+                mapping.oracle_source = *oracle_source as u8;
+                mapping.price_oracle = *price_oracle;
+                mapping.max_price_age = *max_price_age;
+                mapping.max_confidence_bps = *max_confidence_bps;
+                mapping.reset_reference_price(); // Prices from the old feed are not comparable
+            }
+            FactoryChange::SurplusPolicy { surplus_policy } => {
+                factory.surplus_policy = *surplus_policy as u8;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A queued FactoryChange, executable once `eta` has passed.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingChange {
+    pub bump: u8,
+    pub factory: Pubkey,
+    pub id: u64,               // Sequence number from Factory.next_change_id
+    pub change: FactoryChange,
+    pub queued_by: Pubkey,     // Receives the rent back on execute or cancel
+    pub queued_at: i64,
+    pub eta: i64,              // Earliest execution timestamp
+}
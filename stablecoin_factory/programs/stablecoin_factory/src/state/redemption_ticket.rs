@@ -0,0 +1,24 @@
+use super::*;
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TicketStatus {
+    Queued,                    // Coins in escrow, waiting for fiat
+    Cancelled,                 // Coins returned; closed when the queue reaches it
+}
+
+/// A queued redemption. Tickets settle strictly in id order, as fiat becomes
+/// available in the coin's reserve.
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionTicket {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub id: u64,               // Position in the coin's queue
+    pub owner: Pubkey,         // Holder who queued the coins; receives the rent back
+    pub fiat_destination: Pubkey, // Fiat token account paid on settlement
+    pub amount: u64,           // Coins held in the redemption escrow
+    pub fees: FeeBreakdown,    // Fixed when queued, in coin base units
+    pub status: TicketStatus,
+    pub queued_at: i64,
+}
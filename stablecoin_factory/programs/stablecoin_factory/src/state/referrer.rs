@@ -0,0 +1,17 @@
+use super::*;
+
+
+/// Referral earnings and volume for one wallet on one coin. Shares are held in
+/// the coin's referral escrow until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub wallet: Pubkey,            // Signs claims
+    pub referral_count: u64,       // Mints routed through this referrer
+    pub total_volume: u64,         // Coins minted through those mints
+    pub accrued: u64,              // Unclaimed share, in coin base units
+    pub total_claimed: u64,
+    pub registered_at: i64,
+}
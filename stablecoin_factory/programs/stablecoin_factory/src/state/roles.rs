@@ -0,0 +1,71 @@
+use super::*;
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    IssuerAdmin,               // Administers the scope and grants every other role
+    Minter,                    // Issues new coins
+    ComplianceOfficer,         // Freezes, thaws and seizes holder accounts
+    Guardian,                  // Pauses, but never unpauses
+    Treasurer,                 // Withdraws accrued yield
+    RateOracleUpdater,         // Publishes manual bond prices and interest-bearing coin rates
+}
+
+/// Keys holding each role for one scope, either the factory or a single
+/// sovereign coin. An unassigned role holds the default pubkey.
+#[account]
+#[derive(InitSpace)]
+pub struct Roles {
+    pub bump: u8,
+    pub scope: Pubkey,         // The Factory or SovereignCoin these roles apply to
+    
+    pub issuer_admin: Pubkey,
+    pub minter: Pubkey,
+    pub compliance_officer: Pubkey,
+    pub guardian: Pubkey,
+    pub treasurer: Pubkey,
+    pub rate_oracle_updater: Pubkey,
+}
+
+impl Roles {
+    pub fn get(&self, role: Role) -> Pubkey {
+        match role {
+            Role::IssuerAdmin => self.issuer_admin,
+            Role::Minter => self.minter,
+            Role::ComplianceOfficer => self.compliance_officer,
+            Role::Guardian => self.guardian,
+            Role::Treasurer => self.treasurer,
+            Role::RateOracleUpdater => self.rate_oracle_updater,
+        }
+    }
+
+    pub fn set(&mut self, role: Role, key: Pubkey) {
+        match role {
+            Role::IssuerAdmin => self.issuer_admin = key,
+            Role::Minter => self.minter = key,
+            Role::ComplianceOfficer => self.compliance_officer = key,
+            Role::Guardian => self.guardian = key,
+            Role::Treasurer => self.treasurer = key,
+            Role::RateOracleUpdater => self.rate_oracle_updater = key,
+        }
+    }
+
+    /// Whether `key` holds `role`. Unassigned roles are held by nobody.
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        let holder = self.get(role);
+        holder != Pubkey::default() && holder == *key
+    }
+
+    /// Every role starts with `admin` except the guardian, which must be a
+    /// different key and is appointed separately.
+    pub fn init(&mut self, bump: u8, scope: Pubkey, admin: Pubkey) {
+        self.bump = bump;
+        self.scope = scope;
+        self.issuer_admin = admin;
+        self.minter = admin;
+        self.compliance_officer = admin;
+        self.guardian = Pubkey::default();
+        self.treasurer = admin;
+        self.rate_oracle_updater = admin;
+    }
+}
@@ -1,11 +1,47 @@
 use super::*;
 
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MetadataStandard {
+    Metaplex,                  // SPL mint with a Metaplex metadata account
+    Token2022,                 // Token-2022 mint carrying its own TokenMetadata
+}
+
+/// Amount allowed per fixed window of RATE_LIMIT_WINDOW seconds. The counter
+/// resets the first time it is used in a new window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct RateLimit {
+    pub limit: u64,            // Max amount per window
+    pub window_start: i64,     // Start of the current window
+    pub used: u64,             // Amount consumed in the current window
+}
+
+impl RateLimit {
+    pub fn new(limit: u64) -> Self {
+        Self { limit, window_start: 0, used: 0 }
+    }
+
+    /// Records `amount` against the window containing `now`, or returns false
+    /// without recording anything if that would exceed the limit.
+    pub fn try_consume(&mut self, amount: u64, now: i64) -> bool {
+        let window_start = now - now.rem_euclid(RATE_LIMIT_WINDOW);
+        let used = if window_start == self.window_start { self.used } else { 0 };
+        match used.checked_add(amount) {
+            Some(total) if total <= self.limit => {
+                self.window_start = window_start;
+                self.used = total;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct SovereignCoin {
     pub bump: u8,
-    pub authority: Pubkey,     // The issuer of this sovereign coin
+    pub authority: Pubkey,     // The issuer; sets up and closes the coin, other rights live in Roles
     pub factory: Pubkey,       // Reference to the factory that created this coin
     
     // Coin details
@@ -23,6 +59,10 @@ pub struct SovereignCoin {
     pub mint: Pubkey,          // The actual SPL token mint
     pub fiat_reserve: Pubkey,  // Token account holding the fiat token (e.g., USDC)
     pub bond_holding: Pubkey,  // Token account holding the bond tokens
+    pub metadata_standard: MetadataStandard, // Where the name, symbol and URI live
+    pub transfer_hook_enabled: bool, // Transfers go through the compliance hook program
+    pub interest_bearing: bool,      // Holder yield paid through the mint's interest rate
+    pub permanent_delegate_enabled: bool, // Coin PDA can move any balance, for seizures
     
     // Current state
     pub total_supply: u64,     // Current total supply of this sovereign coin
@@ -32,4 +72,137 @@ pub struct SovereignCoin {
     // Reserve amounts (for quick access without querying token accounts)
     pub fiat_amount: u64,      // Current amount of fiat reserves
     pub bond_amount: u64,      // Current amount of bond holdings
+    
+    // Reconciled surplus (held in fiat_reserve / bond_holding)
+    pub protocol_owned_fiat: u64, // Part of fiat_amount that came from surplus
+    pub protocol_owned_bond: u64, // Part of bond_amount that came from surplus
+    pub accrued_yield_fiat: u64,  // Fiat surplus set aside as yield, not backing
+    pub accrued_yield_bond: u64,  // Bond surplus set aside as yield, not backing
+
+    // Oracle valuation (in fiat token base units)
+    pub bond_nav: u64,         // Value of bond holdings at the last oracle price
+    pub reserve_nav: u64,      // Fiat reserve plus bond NAV
+    pub nav_updated_at: i64,   // Timestamp of the last NAV refresh
+
+    // Interest-bearing mode
+    pub holder_rate_bps: i16,        // Rate last set on the mint by harvest_yield
+    pub last_yield_harvest_at: i64,  // Start of the current harvest period
+
+    // Emergency controls
+    pub paused: u8,            // PAUSE_* flags for this coin only
+    
+    // Issuance limits, within the factory's coin_* bounds
+    pub max_supply: u64,             // Mint supply can never exceed this
+    pub mint_limit: RateLimit,       // Issuance per window
+    pub redeem_limit: RateLimit,     // Redemptions per window
+    
+    // Issuer fees, on top of the factory's protocol fee
+    pub fee_schedule: FeeSchedule,
+    
+    // Primary market access
+    pub permissionless: bool,        // Anyone may mint and redeem, not only allowlisted wallets
+    
+    // Flash mints, disabled while flash_mint_cap is zero
+    pub flash_mint_cap: u64,         // Largest single flash mint
+    pub flash_fee_bps: u16,          // Fee on the flash-minted amount, paid in coins
+    pub flash_outstanding: u64,      // Minted by flash_mint and not yet repaid
+    pub flash_fee_due: u64,          // Fee owed by the outstanding flash mint
+    
+    // Redemption queue, settled in ticket id order
+    pub next_ticket_id: u64,         // Id of the next RedemptionTicket
+    pub next_settle_ticket_id: u64,  // Oldest ticket not yet settled or closed
+    pub queued_redemptions: u64,     // Coins held in the redemption escrow
+    
+    // Lifecycle
+    pub finalized: bool,       // Metadata created and counted in the factory totals
+    
+    // Wind-down (orderly shutdown after a bond default)
+    pub winding_down: bool,          // Minting frozen, holders settle pro-rata
+    pub wind_down_started_at: i64,   // Timestamp of begin_wind_down
+    pub wind_down_supply: u64,       // Mint supply at the snapshot
+    pub wind_down_fiat: u64,         // fiat_reserve balance at the snapshot
+    pub wind_down_bond: u64,         // bond_holding balance at the snapshot
+    
+    // Layout, appended in v2; new fields come out of reserved
+    pub version: u8,
+    pub reserved: [u8; SOVEREIGN_COIN_RESERVED_BYTES],
+}
+
+impl SovereignCoin {
+    /// Account size, discriminator included, before version and reserved existed.
+    pub const V1_LEN: usize = 8 + Self::INIT_SPACE - 1 - SOVEREIGN_COIN_RESERVED_BYTES;
+
+    /// Fails if the operation is paused for this coin, either directly or
+    /// through the factory-wide flags.
+    pub fn require_not_paused(&self, factory: &Factory, flags: u8) -> Result<()> {
+        require!(!factory.is_paused(flags), StablecoinError::FactoryPaused);
+        require!(self.paused & flags == 0, StablecoinError::SovereignCoinPaused);
+        Ok(())
+    }
+
+    /// Enforces max_supply and the mint window for issuing `amount` on top of
+    /// `supply`. Call from every path that mints new coins.
+    pub fn record_mint(&mut self, factory: &Factory, amount: u64, supply: u64, now: i64) -> Result<()> {
+        self.clamp_limits(factory);
+        let new_supply = supply.checked_add(amount).ok_or(StablecoinError::ArithmeticOverflow)?;
+        require!(new_supply <= self.max_supply, StablecoinError::MaxSupplyExceeded);
+        require!(self.mint_limit.try_consume(amount, now), StablecoinError::MintRateLimitExceeded);
+        Ok(())
+    }
+
+    /// Enforces the redeem window. Call from every path that redeems coins
+    /// for reserves, except wind-down settlement.
+    pub fn record_redeem(&mut self, factory: &Factory, amount: u64, now: i64) -> Result<()> {
+        self.clamp_limits(factory);
+        require!(self.redeem_limit.try_consume(amount, now), StablecoinError::RedeemRateLimitExceeded);
+        Ok(())
+    }
+
+    /// Lowers limits set before governance tightened the factory bounds to
+    /// those bounds, so a coin never runs above them.
+    fn clamp_limits(&mut self, factory: &Factory) {
+        self.max_supply = self.max_supply.min(factory.coin_max_supply_cap);
+        self.mint_limit.limit = self.mint_limit.limit.min(factory.coin_mint_limit_cap);
+        self.redeem_limit.limit = self.redeem_limit.limit.min(factory.coin_redeem_limit_cap);
+    }
+
+    /// Fails unless the coin is permissionless or the wallet minting or
+    /// redeeming has a PrimaryMarketAllowlist entry.
+    pub fn require_primary_market_access(&self, allowlisted: bool) -> Result<()> {
+        require!(self.permissionless || allowlisted, StablecoinError::NotAllowlisted);
+        Ok(())
+    }
+
+    /// Seeds for signing as the coin PDA, which owns the reserve token accounts.
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            b"sovereign_coin",
+            self.authority.as_ref(),
+            self.symbol_seed(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    /// Symbol bytes as used in the PDA seeds, without the zero padding.
+    pub fn symbol_seed(&self) -> &[u8] {
+        let len = self.symbol.iter().take_while(|&&b| b != 0).count();
+        &self.symbol[..len]
+    }
+
+    /// Fiat backing the coin given the actual `fiat_reserve` balance. Surplus set
+    /// aside as yield sits in the same account but does not back the supply.
+    pub fn backing_fiat(&self, fiat_reserve_balance: u64) -> u64 {
+        fiat_reserve_balance.saturating_sub(self.accrued_yield_fiat)
+    }
+
+    /// Bond tokens backing the coin given the actual `bond_holding` balance.
+    pub fn backing_bond(&self, bond_holding_balance: u64) -> u64 {
+        bond_holding_balance.saturating_sub(self.accrued_yield_bond)
+    }
+
+    /// Target fiat currency code without the zero padding.
+    pub fn fiat_currency_code(&self) -> &[u8] {
+        let len = self.target_fiat_currency.iter().take_while(|&&b| b != 0).count();
+        &self.target_fiat_currency[..len]
+    }
 }
\ No newline at end of file
@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# Records target/compute-units/baseline.json from an older commit, for
+# tests/compute_units.ts to compare the next `anchor test` run against.
+# compute_units.ts is copied in, so it works on commits that predate it.
+#
+#   scripts/compute_units_baseline.sh [commit]    # defaults to before the zero-copy Factory
+set -euo pipefail
+
+ROOT="$(cd "$(dirname "$0")/.." && pwd)"
+REPO="$(git -C "$ROOT" rev-parse --show-toplevel)"
+COMMIT="${1:-$(git -C "$ROOT" log --format=%H --grep='^\[user-050\]' | tail -1)^}"
+WORKTREE="$ROOT/target/cu-baseline"
+SUBDIR="${ROOT#"$REPO"/}"
+
+git -C "$REPO" worktree remove --force "$WORKTREE" 2>/dev/null || true
+git -C "$REPO" worktree add --detach "$WORKTREE" "$COMMIT"
+trap 'git -C "$REPO" worktree remove --force "$WORKTREE"' EXIT
+
+cd "$WORKTREE/$SUBDIR"
+cp "$ROOT/tests/compute_units.ts" tests/
+ln -s "$ROOT/node_modules" node_modules
+# Same program ids as declare_id!, or the deploy would land elsewhere
+mkdir -p target/deploy
+cp "$ROOT"/target/deploy/*-keypair.json target/deploy/
+
+CU_LABEL=baseline CU_OUT_DIR="$ROOT/target/compute-units" anchor test
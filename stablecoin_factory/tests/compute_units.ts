@@ -0,0 +1,120 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { StablecoinFactory } from "../target/types/stablecoin_factory";
+import * as fs from "fs";
+import * as path from "path";
+
+// Compute units per stablecoin_factory instruction, read back from every
+// successful transaction the suite sent. Only top-level calls are counted, so
+// event CPIs are included in their instruction's figure.
+//
+// Results go to target/compute-units/<CU_LABEL>.json, or under CU_OUT_DIR. To
+// compare against an older build, run scripts/compute_units_baseline.sh, then
+// `anchor test -- --features localnet`; the second run prints both side by side.
+const LABEL = process.env.CU_LABEL ?? "current";
+const OUT_DIR = process.env.CU_OUT_DIR ?? path.join(__dirname, "..", "target", "compute-units");
+
+type Stats = { count: number; min: number; max: number; mean: number };
+
+// Walks the invoke stack in the logs and returns [instruction, units] for
+// each successful top-level call into `programId`
+function topLevelUnits(logs: string[], programId: string): [string, number][] {
+  const results: [string, number][] = [];
+  const stack: { program: string; name?: string; units?: number }[] = [];
+  for (const line of logs) {
+    let match: RegExpMatchArray | null;
+    if ((match = line.match(/^Program (\w+) invoke \[\d+\]$/))) {
+      stack.push({ program: match[1] });
+    } else if ((match = line.match(/^Program log: Instruction: (\w+)$/))) {
+      const frame = stack[stack.length - 1];
+      if (frame && frame.program === programId && !frame.name) {
+        frame.name = match[1];
+      }
+    } else if ((match = line.match(/^Program (\w+) consumed (\d+) of \d+ compute units$/))) {
+      const frame = stack[stack.length - 1];
+      if (frame && frame.program === match[1]) {
+        frame.units = Number(match[2]);
+      }
+    } else if ((match = line.match(/^Program (\w+) (success|failed)/))) {
+      const frame = stack.pop();
+      if (
+        stack.length === 0 &&
+        match[2] === "success" &&
+        frame?.program === programId &&
+        frame.name &&
+        frame.units !== undefined
+      ) {
+        results.push([frame.name, frame.units]);
+      }
+    }
+  }
+  return results;
+}
+
+after(async function () {
+  this.timeout(1_000_000);
+
+  const provider = anchor.AnchorProvider.env();
+  const program = anchor.workspace.StablecoinFactory as Program<StablecoinFactory>;
+  const connection = provider.connection;
+  const programId = program.programId.toBase58();
+
+  // Every signature that touched the program, newest first
+  const signatures: string[] = [];
+  let before: string | undefined;
+  for (;;) {
+    const page = await connection.getSignaturesForAddress(
+      program.programId,
+      { before, limit: 1000 },
+      "confirmed"
+    );
+    if (page.length === 0) break;
+    signatures.push(...page.filter((s) => s.err === null).map((s) => s.signature));
+    before = page[page.length - 1].signature;
+  }
+
+  const samples: Record<string, number[]> = {};
+  for (let i = 0; i < signatures.length; i += 100) {
+    const txs = await connection.getTransactions(signatures.slice(i, i + 100), {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    for (const tx of txs) {
+      for (const [name, units] of topLevelUnits(tx?.meta?.logMessages ?? [], programId)) {
+        (samples[name] ??= []).push(units);
+      }
+    }
+  }
+
+  const stats: Record<string, Stats> = {};
+  for (const name of Object.keys(samples).sort()) {
+    const units = samples[name];
+    stats[name] = {
+      count: units.length,
+      min: Math.min(...units),
+      max: Math.max(...units),
+      mean: Math.round(units.reduce((a, b) => a + b, 0) / units.length),
+    };
+  }
+
+  fs.mkdirSync(OUT_DIR, { recursive: true });
+  fs.writeFileSync(path.join(OUT_DIR, `${LABEL}.json`), JSON.stringify(stats, null, 2));
+
+  const baselinePath = path.join(OUT_DIR, "baseline.json");
+  if (LABEL === "baseline" || !fs.existsSync(baselinePath)) {
+    console.table(stats);
+    return;
+  }
+  const baseline: Record<string, Stats> = JSON.parse(fs.readFileSync(baselinePath, "utf8"));
+  const comparison: Record<string, object> = {};
+  for (const name of Object.keys(stats)) {
+    const was = baseline[name]?.mean;
+    const now = stats[name].mean;
+    comparison[name] = {
+      baseline: was ?? "-",
+      current: now,
+      change: was ? `${(((now - was) / was) * 100).toFixed(1)}%` : "-",
+    };
+  }
+  console.table(comparison);
+});
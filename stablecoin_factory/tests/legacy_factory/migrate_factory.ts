@@ -0,0 +1,164 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { StablecoinFactory } from "../../target/types/stablecoin_factory";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import * as fs from "fs";
+import * as path from "path";
+
+// Runs once per legacy layout, against the factory fixture loaded by
+// tests/legacy_factory/v<N>/Test.toml
+const VERSION = Number(process.env.LEGACY_FACTORY_VERSION);
+
+describe(`factory migration from v${VERSION}`, () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.StablecoinFactory as Program<StablecoinFactory>;
+
+  const [factoryPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("factory")],
+    program.programId
+  );
+  const [factoryRoles] = PublicKey.findProgramAddressSync(
+    [Buffer.from("roles"), factoryPDA.toBuffer()],
+    program.programId
+  );
+  // Issuer admin in tests/fixtures/factory_legacy_roles.json
+  const legacyIssuer = Keypair.fromSecretKey(Uint8Array.from(JSON.parse(
+    fs.readFileSync(path.join(__dirname, "..", "fixtures", "legacy_issuer.json"), "utf8")
+  )));
+
+  const currency = (code: string) => [...Buffer.from(code), ...Array(8 - code.length).fill(0)];
+
+  before(async () => {
+    const sig = await provider.connection.requestAirdrop(legacyIssuer.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+  });
+
+  function migrateFactory() {
+    return program.methods
+      .migrateFactory()
+      .accounts({
+        authority: legacyIssuer.publicKey,
+        factory: factoryPDA,
+        factoryRoles,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([legacyIssuer])
+      .rpc();
+  }
+
+  it("Should fail to deserialize the fixture before migration", async () => {
+    const info = await provider.connection.getAccountInfo(factoryPDA);
+    // Borsh v1 layout, plus version and reserved from v2
+    expect(info.data.length).to.equal(VERSION === 1 ? 832 : 832 + 1 + 128);
+
+    let decoded = true;
+    try {
+      await program.account.factory.fetch(factoryPDA);
+    } catch (err) {
+      decoded = false;
+    }
+    expect(decoded).to.be.false;
+  });
+
+  it("Rewrites the fixture in the zero-copy layout", async () => {
+    await migrateFactory();
+
+    const info = await provider.connection.getAccountInfo(factoryPDA);
+    expect(info.data.length).to.equal(program.account.factory.size);
+
+    const factory = await program.account.factory.fetch(factoryPDA);
+    expect(factory.version).to.equal(3);
+    expect(factory.reserved.every((byte: number) => byte === 0)).to.be.true;
+    expect(factory.authority.toString()).to.equal(legacyIssuer.publicKey.toString());
+    expect(factory.treasury.toString()).to.equal(legacyIssuer.publicKey.toString());
+    expect(factory.totalSovereignCoins.toNumber()).to.equal(3);
+    expect(new anchor.BN(factory.totalSupplyAllCoins, "le").toString()).to.equal("75000000000");
+    expect(factory.bondRatingOrdinals).to.deep.equal([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    expect(factory.minFiatReservePercentage).to.equal(20);
+    expect(factory.bondReserveMultiplier).to.equal(30);
+    expect(factory.yieldShareProtocol).to.equal(40);
+    expect(factory.yieldShareIssuer).to.equal(30);
+    expect(factory.yieldShareHolders).to.equal(30);
+    expect(factory.mintFeeBps).to.equal(25);
+    expect(factory.burnFeeBps).to.equal(10);
+    expect(factory.maxIssuerFeeBps).to.equal(100);
+    expect(factory.swapFeeBps).to.equal(30);
+    expect(factory.referralShareBps).to.equal(2_000);
+    expect(factory.surplusPolicy).to.equal(1); // Yield
+    expect(factory.paused).to.equal(0);
+    expect(factory.coinMaxSupplyCap.toString()).to.equal("1000000000000");
+    expect(factory.coinMintLimitCap.toString()).to.equal("100000000000");
+    expect(factory.coinRedeemLimitCap.toString()).to.equal("50000000000");
+    expect(factory.timelockDelay.toNumber()).to.equal(86_400);
+    expect(factory.nextChangeId.toNumber()).to.equal(7);
+    expect(factory.outflowLimit.toString()).to.equal("500000000000");
+    expect(factory.rebalanceBandBps).to.equal(200);
+    expect(factory.keeperRewardBps).to.equal(10);
+    expect(factory.insuranceFeeShareBps).to.equal(1_000);
+    expect(factory.coinCreationFee.toNumber()).to.equal(100_000_000);
+
+    // The breaker enum is split across kind, currency and drop fields
+    expect(factory.maxPriceDropBps).to.equal(500);
+    expect(factory.breakerFlags).to.equal(3);
+    expect(factory.breakerPaused).to.equal(3);
+    expect(factory.breakerTrippedAt.toNumber()).to.equal(1_700_000_600);
+    if (VERSION === 1) {
+      expect(factory.breakerTripKind).to.equal(1); // Outflow
+      expect(factory.breakerTripCurrency).to.deep.equal(currency(""));
+      expect(factory.breakerTripDropBps).to.equal(0);
+    } else {
+      expect(factory.breakerTripKind).to.equal(2); // PriceDrop
+      expect(factory.breakerTripCurrency).to.deep.equal(currency("EUR"));
+      expect(factory.breakerTripDropBps).to.equal(750);
+    }
+
+    expect(factory.bondMappingsCount).to.equal(2);
+    const [usd, eur] = factory.bondMappings;
+    expect(usd.active).to.equal(1);
+    expect(usd.fiatCurrency).to.deep.equal(currency("USD"));
+    expect(usd.bondMint.toString()).to.equal(new PublicKey(Buffer.alloc(32, 0x11)).toString());
+    expect(usd.bondRating).to.equal(1);
+    expect(usd.oracleSource).to.equal(1); // Pyth
+    expect(usd.priceOracle.toString()).to.equal(new PublicKey(Buffer.alloc(32, 0x21)).toString());
+    expect(usd.maxPriceAge).to.equal(60);
+    expect(usd.maxConfidenceBps).to.equal(100);
+    expect(usd.referencePrice.toNumber()).to.equal(101_000_000);
+    expect(usd.referenceExpo).to.equal(-8);
+    expect(usd.referenceWindowStart.toNumber()).to.equal(1_700_000_000);
+    expect(eur.fiatCurrency).to.deep.equal(currency("EUR"));
+    expect(eur.bondMint.toString()).to.equal(new PublicKey(Buffer.alloc(32, 0x12)).toString());
+    expect(eur.bondRating).to.equal(2);
+    expect(eur.oracleSource).to.equal(2); // Manual
+    expect(eur.maxPriceAge).to.equal(3_600);
+    expect(eur.referencePrice.toNumber()).to.equal(98_500_000);
+
+    // Coins are counted onto their mapping as each one is migrated
+    for (const mapping of [usd, eur]) {
+      expect(mapping.coinCount.toNumber()).to.equal(0);
+      expect(mapping.previousBondMint.toString()).to.equal(PublicKey.default.toString());
+      expect(mapping.previousCoinCount.toNumber()).to.equal(0);
+    }
+
+    // The single outflow window is carried into each currency
+    for (const mapping of [usd, eur]) {
+      expect(mapping.outflowWindowStart.toNumber()).to.equal(1_700_000_000);
+      expect(mapping.outflowUsed.toNumber()).to.equal(12_345_678);
+    }
+    for (const mapping of factory.bondMappings.slice(2)) {
+      expect(mapping.active).to.equal(0);
+      expect(mapping.bondMint.toString()).to.equal(PublicKey.default.toString());
+    }
+  });
+
+  it("Should fail to migrate the factory twice", async () => {
+    try {
+      await migrateFactory();
+      expect.fail("Transaction should have failed with already migrated");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AccountAlreadyMigrated");
+    }
+  });
+});
@@ -1,11 +1,17 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { StablecoinFactory } from "../target/types/stablecoin_factory";
-import { PublicKey, Keypair } from "@solana/web3.js";
+import { PublicKey, Keypair, Transaction } from "@solana/web3.js";
 import { 
   createMint, 
   getOrCreateAssociatedTokenAccount,
-  mintTo
+  mintTo,
+  getTokenMetadata,
+  getMint,
+  getAccount,
+  getInterestBearingMintConfigState,
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID
 } from "@solana/spl-token";
 import { expect } from "chai";
 import * as fs from "fs";
@@ -32,6 +38,20 @@ describe("stablecoin_factory", () => {
     program.programId
   );
 
+  // Roles PDA of the factory or of a sovereign coin
+  const rolesPDA = (scope: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("roles"), scope.toBuffer()],
+      program.programId
+    )[0];
+
+  // Onboarding profile of an issuer
+  const issuerProfilePDA = (issuer: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer_profile"), issuer.toBuffer()],
+      program.programId
+    )[0];
+
   // Test parameters for factory initialization
   const minFiatReserve = 20; // This is the 20% minimum reserve percentage mentioned in the formula "Fiat Reserve = 20 + (Ordinal - 1) × 30/9".
   const bondReserveMultiplier = 30; // This is the multiplier (30) from the same formula that adjusts reserve requirements based on bond rating.
@@ -39,6 +59,7 @@ describe("stablecoin_factory", () => {
   const yieldShareIssuer = 15; // 15% for the sovereign coin issuers
   const yieldShareHolders = 80; // 80% for the coin holders/stakers
   // Total of yield shares is 100%
+  const MIN_TIMELOCK_DELAY = 2; // Seconds, as the program is built with the localnet feature
 
   let factoryInitialized = false;
 
@@ -163,6 +184,7 @@ describe("stablecoin_factory", () => {
           .accounts({
             authority: authority,
             factory: factoryPDA,
+            factoryRoles: rolesPDA(factoryPDA),
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .rpc();
@@ -178,7 +200,7 @@ describe("stablecoin_factory", () => {
       expect(factoryAccount.authority.toString()).to.equal(authority.toString());
       expect(factoryAccount.treasury.toString()).to.equal(authority.toString());
       expect(factoryAccount.totalSovereignCoins.toNumber()).to.equal(0);
-      expect(factoryAccount.totalSupplyAllCoins.toNumber()).to.equal(0);
+      expect(Buffer.from(factoryAccount.totalSupplyAllCoins).equals(Buffer.alloc(16))).to.be.true;
       expect(factoryAccount.minFiatReservePercentage).to.equal(minFiatReserve);
       expect(factoryAccount.bondReserveMultiplier).to.equal(bondReserveMultiplier);
       expect(factoryAccount.yieldShareProtocol).to.equal(yieldShareProtocol);
@@ -230,7 +252,7 @@ describe("stablecoin_factory", () => {
       const mapping = factoryAccount.bondMappings[mappingIndex];
 
       // Verify all mapping fields were set correctly
-      expect(mapping.active).to.equal(true);
+      expect(mapping.active).to.equal(1);
       
       // Check fiat currency bytes
       const storedFiatBytes = mapping.fiatCurrency.filter(byte => byte !== 0);
@@ -351,6 +373,66 @@ describe("stablecoin_factory", () => {
     }
   });
 
+  // Issuer Onboarding Tests
+  it("Can register as an issuer", async () => {
+    await program.methods
+      .registerIssuer("US")
+      .accounts({
+        issuer: authority,
+        issuerProfile: issuerProfilePDA(authority),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const profile = await program.account.issuerProfile.fetch(issuerProfilePDA(authority));
+    expect(profile.issuer.toString()).to.equal(authority.toString());
+    expect(profile.kycStatus).to.deep.equal({ pending: {} });
+    expect(Buffer.from(profile.jurisdiction).toString().replace(/\0/g, "")).to.equal("US");
+  });
+
+  it("Should fail to create a coin before the issuer is approved", async () => {
+    const [sovereignCoinPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from("USDP")],
+      program.programId
+    );
+    try {
+      await program.methods
+        .initSovereignCoin({ name: "US Dollar Pending", symbol: "USDP", uri: "", fiatCurrency: "USD" })
+        .accounts({
+          payer: authority,
+          authority: authority,
+          factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
+          sovereignCoin: sovereignCoinPDA,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+      expect.fail("Transaction should have failed with issuer not approved");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("IssuerNotApproved");
+    }
+  });
+
+  it("Can approve an issuer", async () => {
+    await program.methods
+      .approveIssuer(100)
+      .accounts({
+        authority: authority,
+        factory: factoryPDA,
+        factoryRoles: rolesPDA(factoryPDA),
+        issuerProfile: issuerProfilePDA(authority),
+      })
+      .rpc();
+
+    const profile = await program.account.issuerProfile.fetch(issuerProfilePDA(authority));
+    expect(profile.kycStatus).to.deep.equal({ approved: {} });
+    expect(profile.coinQuota).to.equal(100);
+  });
+
   // Initialize Sovereign Coin Tests
   it("Can initialize a USD sovereign coin", async () => {
     // Skip if USD bond mapping not registered
@@ -394,6 +476,8 @@ describe("stablecoin_factory", () => {
           payer: authority,
           authority: authority,
           factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
           sovereignCoin: sovereignCoinPDA,
           fiatTokenMint: usdFiatMint,
           bondTokenMint: usdBondMint,
@@ -487,6 +571,8 @@ describe("stablecoin_factory", () => {
           payer: authority,
           authority: authority,
           factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
           sovereignCoin: sovereignCoinPDA,
           fiatTokenMint: eurFiatMint,
           bondTokenMint: eurBondMint,
@@ -541,6 +627,8 @@ describe("stablecoin_factory", () => {
           payer: authority,
           authority: authority,
           factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
           sovereignCoin: sovereignCoinPDA,
           fiatTokenMint: usdFiatMint, // Doesn't matter which one we use here
           bondTokenMint: usdBondMint, // Doesn't matter which one we use here
@@ -583,6 +671,8 @@ describe("stablecoin_factory", () => {
           payer: authority,
           authority: authority,
           factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
           sovereignCoin: sovereignCoinPDA,
           fiatTokenMint: usdFiatMint,
           bondTokenMint: eurBondMint, // Wrong bond mint for USD
@@ -625,6 +715,8 @@ describe("stablecoin_factory", () => {
           payer: authority,
           authority: authority,
           factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
           sovereignCoin: sovereignCoinPDA,
           fiatTokenMint: usdFiatMint,
           bondTokenMint: usdBondMint,
@@ -639,4 +731,3778 @@ describe("stablecoin_factory", () => {
       expect(err.error.errorCode.code).to.equal("NameTooLong");
     }
   });
-});
\ No newline at end of file
+
+  // Oracle and NAV Tests
+  const [usdsCoinPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from("USDS")],
+    program.programId
+  );
+  const usdsMint = Keypair.generate();
+  const usdsFiatReserve = Keypair.generate();
+  const usdsBondHolding = Keypair.generate();
+
+  function manualPricePDA(bondMint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("manual_price"), bondMint.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  it("Can initialize and update a manual bond price", async () => {
+    const manualPrice = manualPricePDA(usdBondMint);
+
+    await program.methods
+      .initManualPrice(-6)
+      .accounts({
+        authority: authority,
+        factory: factoryPDA,
+        manualPrice: manualPrice,
+        bondTokenMint: usdBondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // 1 bond token = 1.02 USD, +/- 0.001
+    await program.methods
+      .updateManualPrice(new anchor.BN(1_020_000), new anchor.BN(1_000))
+      .accounts({
+        authority: authority,
+        factory: factoryPDA,
+        manualPrice: manualPrice,
+      })
+      .rpc();
+
+    const priceAccount = await program.account.manualPrice.fetch(manualPrice);
+    expect(priceAccount.bondMint.toString()).to.equal(usdBondMint.toString());
+    expect(priceAccount.price.toNumber()).to.equal(1_020_000);
+    expect(priceAccount.conf.toNumber()).to.equal(1_000);
+    expect(priceAccount.expo).to.equal(-6);
+    expect(priceAccount.publishTime.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("Should fail to update a manual price with a non-positive price", async () => {
+    try {
+      await program.methods
+        .updateManualPrice(new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          manualPrice: manualPricePDA(usdBondMint),
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with invalid oracle price");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidOraclePrice");
+    }
+  });
+
+  it("Can configure a manual oracle for the USD bond", async () => {
+    const manualPrice = manualPricePDA(usdBondMint);
+
+    await applyFactoryChange({
+      bondOracle: {
+        fiatCurrency: Array.from(Buffer.concat([Buffer.from("USD"), Buffer.alloc(5)])),
+        oracleSource: { manual: {} },
+        priceOracle: manualPrice,
+        maxPriceAge: 3600,
+        maxConfidenceBps: 100,
+      },
+    });
+
+    const factoryAccount = await program.account.factory.fetch(factoryPDA);
+    const mapping = factoryAccount.bondMappings.find(
+      (m) => m.active && Buffer.from(m.fiatCurrency.filter(b => b !== 0)).toString() === "USD"
+    );
+    expect(mapping.oracleSource).to.equal(2); // OracleSource::Manual
+    expect(mapping.priceOracle.toString()).to.equal(manualPrice.toString());
+    expect(mapping.maxPriceAge).to.equal(3600);
+    expect(mapping.maxConfidenceBps).to.equal(100);
+  });
+
+  it("Should fail to configure a manual oracle that is not the bond's price PDA", async () => {
+    try {
+      await applyFactoryChange({
+        bondOracle: {
+          fiatCurrency: Array.from(Buffer.concat([Buffer.from("USD"), Buffer.alloc(5)])),
+          oracleSource: { manual: {} },
+          priceOracle: manualPricePDA(eurBondMint),
+          maxPriceAge: 3600,
+          maxConfidenceBps: 100,
+        },
+      });
+
+      expect.fail("Transaction should have failed with invalid oracle account");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidOracleAccount");
+    }
+  });
+
+  it("Can set up the USD sovereign coin reserve accounts", async () => {
+    await program.methods
+      .setupTokenAccounts()
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: usdsCoinPDA,
+        fiatReserve: usdsFiatReserve.publicKey,
+        bondHolding: usdsBondHolding.publicKey,
+        fiatTokenMint: usdFiatMint,
+        bondTokenMint: usdBondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([usdsFiatReserve, usdsBondHolding])
+      .rpc();
+
+    const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+    expect(sovereignCoinAccount.fiatReserve.toString()).to.equal(usdsFiatReserve.publicKey.toString());
+    expect(sovereignCoinAccount.bondHolding.toString()).to.equal(usdsBondHolding.publicKey.toString());
+  });
+
+  it("Can refresh the reserve NAV from the manual price", async () => {
+    await program.methods
+      .refreshReserveNav()
+      .accounts({
+        sovereignCoin: usdsCoinPDA,
+        factory: factoryPDA,
+        priceOracle: manualPricePDA(usdBondMint),
+        bondTokenMint: usdBondMint,
+        fiatReserve: usdsFiatReserve.publicKey,
+        bondHolding: usdsBondHolding.publicKey,
+        fiatTokenMint: usdFiatMint,
+      })
+      .rpc();
+
+    const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+    const expectedBondNav = Math.floor(sovereignCoinAccount.bondAmount.toNumber() * 1.02);
+    expect(sovereignCoinAccount.bondNav.toNumber()).to.equal(expectedBondNav);
+    expect(sovereignCoinAccount.reserveNav.toNumber()).to.equal(
+      sovereignCoinAccount.fiatAmount.toNumber() + expectedBondNav
+    );
+    expect(sovereignCoinAccount.navUpdatedAt.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("Should fail to refresh NAV when the price confidence is too wide", async () => {
+    const manualPrice = manualPricePDA(usdBondMint);
+
+    // 5% confidence against a 1% tolerance
+    await program.methods
+      .updateManualPrice(new anchor.BN(1_000_000), new anchor.BN(50_000))
+      .accounts({
+        authority: authority,
+        factory: factoryPDA,
+        manualPrice: manualPrice,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .refreshReserveNav()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          priceOracle: manualPrice,
+          bondTokenMint: usdBondMint,
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+          fiatTokenMint: usdFiatMint,
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with too wide confidence");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("OracleConfidenceTooWide");
+    } finally {
+      await program.methods
+        .updateManualPrice(new anchor.BN(1_020_000), new anchor.BN(1_000))
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          manualPrice: manualPrice,
+        })
+        .rpc();
+    }
+  });
+
+  it("Should fail to refresh NAV with an unconfigured price account", async () => {
+    try {
+      await program.methods
+        .refreshReserveNav()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          priceOracle: Keypair.generate().publicKey,
+          bondTokenMint: usdBondMint,
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+          fiatTokenMint: usdFiatMint,
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with invalid oracle account");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidOracleAccount");
+    }
+  });
+
+  // Proof of Reserves Tests
+  it("Can set up the USD sovereign coin mint", async () => {
+    await program.methods
+      .setupMint()
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: usdsCoinPDA,
+        mint: usdsMint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([usdsMint])
+      .rpc();
+
+    const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+    expect(sovereignCoinAccount.mint.toString()).to.equal(usdsMint.publicKey.toString());
+  });
+
+  it("Can read the reserve status as return data", async () => {
+    const status = await program.methods
+      .getReserveStatus()
+      .accounts({
+        sovereignCoin: usdsCoinPDA,
+        factory: factoryPDA,
+        mint: usdsMint.publicKey,
+        fiatReserve: usdsFiatReserve.publicKey,
+        bondHolding: usdsBondHolding.publicKey,
+        priceOracle: manualPricePDA(usdBondMint),
+        bondTokenMint: usdBondMint,
+        fiatTokenMint: usdFiatMint,
+        insuranceVault: null,
+      })
+      .view();
+
+    const FULLY_BACKED = 1 << 0;
+    const FIAT_BUFFER_MET = 1 << 1;
+    const PRICE_VALID = 1 << 2;
+
+    // Nothing has been minted yet, so an empty reserve fully backs the coin
+    expect(status.sovereignCoin.toString()).to.equal(usdsCoinPDA.toString());
+    expect(status.totalSupply.toNumber()).to.equal(0);
+    expect(status.fiatReserveBalance.toNumber()).to.equal(0);
+    expect(status.bondHoldingBalance.toNumber()).to.equal(0);
+    expect(status.collateralRatioBps.toNumber()).to.equal(0);
+    expect(status.requiredReserveRatioBps.toNumber()).to.equal(
+      calculateRequiredReserve(minFiatReserve, 1, bondReserveMultiplier) * 100
+    );
+    expect(status.flags & FULLY_BACKED).to.equal(FULLY_BACKED);
+    expect(status.flags & FIAT_BUFFER_MET).to.equal(FIAT_BUFFER_MET);
+    expect(status.flags & PRICE_VALID).to.equal(PRICE_VALID);
+  });
+
+  it("Reports an invalid price in the reserve status instead of failing", async () => {
+    const status = await program.methods
+      .getReserveStatus()
+      .accounts({
+        sovereignCoin: usdsCoinPDA,
+        factory: factoryPDA,
+        mint: usdsMint.publicKey,
+        fiatReserve: usdsFiatReserve.publicKey,
+        bondHolding: usdsBondHolding.publicKey,
+        priceOracle: Keypair.generate().publicKey,
+        bondTokenMint: usdBondMint,
+        fiatTokenMint: usdFiatMint,
+        insuranceVault: null,
+      })
+      .view();
+
+    expect(status.bondNav.toNumber()).to.equal(0);
+    expect(status.flags & (1 << 2)).to.equal(0);
+  });
+
+  // Reserve Reconciliation Tests
+  it("Folds a donated fiat balance into the reserves under the protocol-owned policy", async () => {
+    const donation = 5_000_000;
+    const before = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+
+    await mintTo(
+      provider.connection,
+      mintAuthority,
+      usdFiatMint,
+      usdsFiatReserve.publicKey,
+      mintAuthority,
+      donation
+    );
+
+    await program.methods
+      .syncReserves()
+      .accounts({
+        sovereignCoin: usdsCoinPDA,
+        factory: factoryPDA,
+        fiatReserve: usdsFiatReserve.publicKey,
+        bondHolding: usdsBondHolding.publicKey,
+      })
+      .rpc();
+
+    const after = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+    expect(after.fiatAmount.toNumber()).to.equal(before.fiatAmount.toNumber() + donation);
+    expect(after.protocolOwnedFiat.toNumber()).to.equal(before.protocolOwnedFiat.toNumber() + donation);
+    expect(after.accruedYieldFiat.toNumber()).to.equal(before.accruedYieldFiat.toNumber());
+  });
+
+  it("Sets a donated fiat balance aside as yield under the yield policy", async () => {
+    const donation = 2_000_000;
+
+    await applyFactoryChange({ surplusPolicy: { surplusPolicy: { yield: {} } } });
+
+    const before = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+
+    await mintTo(
+      provider.connection,
+      mintAuthority,
+      usdFiatMint,
+      usdsFiatReserve.publicKey,
+      mintAuthority,
+      donation
+    );
+
+    try {
+      await program.methods
+        .syncReserves()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+        })
+        .rpc();
+
+      const after = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+      expect(after.fiatAmount.toNumber()).to.equal(before.fiatAmount.toNumber());
+      expect(after.accruedYieldFiat.toNumber()).to.equal(before.accruedYieldFiat.toNumber() + donation);
+    } finally {
+      await applyFactoryChange({ surplusPolicy: { surplusPolicy: { protocolOwned: {} } } });
+    }
+  });
+
+  it("Should fail to sync reserves against a foreign token account", async () => {
+    try {
+      await program.methods
+        .syncReserves()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          fiatReserve: usdsBondHolding.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with a has_one violation");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ConstraintHasOne");
+    }
+  });
+
+  // Emergency Pause Tests
+  const guardian = Keypair.generate();
+  const PAUSE_MINT = 1 << 0;
+  const PAUSE_REDEEM = 1 << 1;
+  const PAUSE_YIELD = 1 << 2;
+  const PAUSE_TRANSFER = 1 << 3;
+
+  it("Should fail to appoint the authority as guardian", async () => {
+    try {
+      await program.methods
+        .grantRole({ guardian: {} }, authority)
+        .accounts({
+          admin: authority,
+          roles: rolesPDA(factoryPDA),
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with guardian is authority");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("GuardianIsAuthority");
+    }
+  });
+
+  it("Can appoint a guardian", async () => {
+    await program.methods
+      .grantRole({ guardian: {} }, guardian.publicKey)
+      .accounts({
+        admin: authority,
+        roles: rolesPDA(factoryPDA),
+      })
+      .rpc();
+
+    const factoryRoles = await program.account.roles.fetch(rolesPDA(factoryPDA));
+    expect(factoryRoles.guardian.toString()).to.equal(guardian.publicKey.toString());
+
+    const factoryAccount = await program.account.factory.fetch(factoryPDA);
+    expect(factoryAccount.paused).to.equal(0);
+  });
+
+  it("Guardian can pause the factory and paused operations are rejected", async () => {
+    await program.methods
+      .pauseFactory(PAUSE_YIELD)
+      .accounts({
+        guardian: guardian.publicKey,
+        factory: factoryPDA,
+      })
+      .signers([guardian])
+      .rpc();
+
+    const factoryAccount = await program.account.factory.fetch(factoryPDA);
+    expect(factoryAccount.paused).to.equal(PAUSE_YIELD);
+
+    try {
+      await program.methods
+        .syncReserves()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with factory paused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("FactoryPaused");
+    }
+  });
+
+  it("Guardian cannot unpause the factory", async () => {
+    try {
+      await program.methods
+        .unpauseFactory(PAUSE_YIELD)
+        .accounts({
+          authority: guardian.publicKey,
+          factory: factoryPDA,
+        })
+        .signers([guardian])
+        .rpc();
+
+      expect.fail("Transaction should have failed with unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("Authority can unpause the factory", async () => {
+    await program.methods
+      .unpauseFactory(PAUSE_YIELD)
+      .accounts({
+        authority: authority,
+        factory: factoryPDA,
+      })
+      .rpc();
+
+    const factoryAccount = await program.account.factory.fetch(factoryPDA);
+    expect(factoryAccount.paused).to.equal(0);
+  });
+
+  it("Guardian can pause a single sovereign coin", async () => {
+    await program.methods
+      .pauseSovereignCoin(PAUSE_MINT | PAUSE_YIELD)
+      .accounts({
+        guardian: guardian.publicKey,
+        factory: factoryPDA,
+        sovereignCoin: usdsCoinPDA,
+      })
+      .signers([guardian])
+      .rpc();
+
+    try {
+      await program.methods
+        .syncReserves()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with sovereign coin paused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SovereignCoinPaused");
+    }
+
+    await program.methods
+      .unpauseSovereignCoin(PAUSE_MINT | PAUSE_YIELD)
+      .accounts({
+        authority: authority,
+        factory: factoryPDA,
+        sovereignCoin: usdsCoinPDA,
+      })
+      .rpc();
+
+    const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+    expect(sovereignCoinAccount.paused).to.equal(0);
+  });
+
+  it("Should fail to pause transfers on a coin without the compliance hook", async () => {
+    try {
+      await program.methods
+        .pauseSovereignCoin(PAUSE_TRANSFER)
+        .accounts({
+          guardian: guardian.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: usdsCoinPDA,
+        })
+        .signers([guardian])
+        .rpc();
+      expect.fail("Transaction should have failed with transfer pause unsupported");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("TransferPauseUnsupported");
+    }
+  });
+
+  it("Should fail to pause with unknown flags", async () => {
+    try {
+      await program.methods
+        .pauseFactory(1 << 7)
+        .accounts({
+          guardian: guardian.publicKey,
+          factory: factoryPDA,
+        })
+        .signers([guardian])
+        .rpc();
+
+      expect.fail("Transaction should have failed with invalid pause flags");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidPauseFlags");
+    }
+  });
+
+  // Token-2022 Metadata Tests
+  it("Can create a Token-2022 coin with metadata stored on the mint", async () => {
+    const coinArgs = {
+      name: "US Dollar Token-2022",
+      symbol: "USD22",
+      uri: "https://example.com/usd22.json",
+      fiatCurrency: "USD"
+    };
+    const [sovereignCoinPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from(coinArgs.symbol)],
+      program.programId
+    );
+    const mint = Keypair.generate();
+
+    await program.methods
+      .initSovereignCoin(coinArgs)
+      .accounts({
+        payer: authority,
+        authority: authority,
+        factory: factoryPDA,
+        issuerProfile: issuerProfilePDA(authority),
+        treasury: authority,
+        sovereignCoin: sovereignCoinPDA,
+        fiatTokenMint: usdFiatMint,
+        bondTokenMint: usdBondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    await program.methods
+      .setupToken2022Mint({ transferHook: false, interestBearing: false, permanentDelegate: false })
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        mint: mint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    const factoryBefore = await program.account.factory.fetch(factoryPDA);
+
+    await program.methods
+      .finalizeSetup()
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        factory: factoryPDA,
+        mint: mint.publicKey,
+        metadata: null,
+        tokenMetadataProgram: null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    const sovereignCoinAccount = await program.account.sovereignCoin.fetch(sovereignCoinPDA);
+    expect(sovereignCoinAccount.metadataStandard).to.deep.equal({ token2022: {} });
+    expect(sovereignCoinAccount.finalized).to.equal(true);
+
+    const factoryAfter = await program.account.factory.fetch(factoryPDA);
+    expect(factoryAfter.totalSovereignCoins.toNumber()).to.equal(factoryBefore.totalSovereignCoins.toNumber() + 1);
+
+    // Name, symbol and URI are read straight from the mint
+    const tokenMetadata = await getTokenMetadata(provider.connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(tokenMetadata.name).to.equal(coinArgs.name);
+    expect(tokenMetadata.symbol).to.equal(coinArgs.symbol);
+    expect(tokenMetadata.uri).to.equal(coinArgs.uri);
+    expect(tokenMetadata.updateAuthority.toString()).to.equal(authority.toString());
+  });
+
+  it("Should fail to finalize a Metaplex coin without the metadata accounts", async () => {
+    try {
+      await program.methods
+        .finalizeSetup()
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          mint: usdsMint.publicKey,
+          metadata: null,
+          tokenMetadataProgram: null,
+          tokenProgram: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      expect.fail("Transaction should have failed with missing metadata account");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MissingMetadataAccount");
+    }
+  });
+
+  // Wind-down Tests
+  async function createSovereignCoin(symbol: string, fiatCurrency: string, fiatMint: PublicKey, bondMint: PublicKey) {
+    const [sovereignCoin] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from(symbol)],
+      program.programId
+    );
+    const mint = Keypair.generate();
+    const fiatReserve = Keypair.generate();
+    const bondHolding = Keypair.generate();
+
+    await program.methods
+      .initSovereignCoin({
+        name: `${symbol} Sovereign`,
+        symbol,
+        uri: `https://example.com/${symbol.toLowerCase()}.json`,
+        fiatCurrency,
+      })
+      .accounts({
+        payer: authority,
+        authority: authority,
+        factory: factoryPDA,
+        issuerProfile: issuerProfilePDA(authority),
+        treasury: authority,
+        sovereignCoin: sovereignCoin,
+        fiatTokenMint: fiatMint,
+        bondTokenMint: bondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    await program.methods
+      .setupMint()
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoin,
+        mint: mint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([mint])
+      .rpc();
+
+    await program.methods
+      .setupTokenAccounts()
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoin,
+        fiatReserve: fiatReserve.publicKey,
+        bondHolding: bondHolding.publicKey,
+        fiatTokenMint: fiatMint,
+        bondTokenMint: bondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([fiatReserve, bondHolding])
+      .rpc();
+
+    return {
+      sovereignCoin,
+      mint: mint.publicKey,
+      fiatReserve: fiatReserve.publicKey,
+      bondHolding: bondHolding.publicKey,
+    };
+  }
+
+  // Issues coins through the primary market against fiat minted to the test
+  // wallet, which is also the treasury and the issuer of every test coin
+  async function issueCoins(
+    coin: { sovereignCoin: PublicKey; mint: PublicKey; fiatReserve: PublicKey },
+    fiatMint: PublicKey,
+    destination: PublicKey,
+    amount: number,
+    tokenProgram = TOKEN_PROGRAM_ID
+  ) {
+    const walletCoinAccount = (await getOrCreateAssociatedTokenAccount(
+      provider.connection, mintAuthority, coin.mint, authority, false, undefined, undefined, tokenProgram
+    )).address;
+    const walletFiatAccount = (await getOrCreateAssociatedTokenAccount(
+      provider.connection, mintAuthority, fiatMint, authority
+    )).address;
+    await mintTo(provider.connection, mintAuthority, fiatMint, walletFiatAccount, mintAuthority, amount);
+
+    await program.methods
+      .mintSovereignCoin(new anchor.BN(amount))
+      .accounts({
+        user: authority,
+        factory: factoryPDA,
+        sovereignCoin: coin.sovereignCoin,
+        mint: coin.mint,
+        fiatReserve: coin.fiatReserve,
+        fiatTokenMint: fiatMint,
+        userFiatAccount: walletFiatAccount,
+        userCoinAccount: destination,
+        treasuryCoinAccount: walletCoinAccount,
+        issuerCoinAccount: walletCoinAccount,
+        insuranceVault: null,
+        marketMaker: null,
+        allowlistEntry: null,
+        referrer: null,
+        referralEscrow: null,
+        tokenProgram,
+        fiatTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  // Queues a factory change and executes it once the timelock delay has
+  // passed. The suites run against a localnet build, where that is seconds
+  async function applyFactoryChange(change: any) {
+    const factory = await program.account.factory.fetch(factoryPDA);
+    const [pendingChange] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_change"), factoryPDA.toBuffer(), factory.nextChangeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .queueFactoryChange(change)
+      .accounts({ authority: authority, factory: factoryPDA, pendingChange })
+      .rpc();
+    await sleep(factory.timelockDelay.toNumber() * 1000 + 1000);
+    await program.methods
+      .executeFactoryChange()
+      .accounts({ authority: authority, factory: factoryPDA, pendingChange, queuedBy: authority })
+      .rpc();
+  }
+
+  async function balance(account: PublicKey) {
+    return Number((await getAccount(provider.connection, account)).amount);
+  }
+
+  describe("wind-down", () => {
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let holderCoinAccount: PublicKey;
+    let holderFiatAccount: PublicKey;
+    let holderBondAccount: PublicKey;
+
+    // 100 coins backed by 100 USD and 10 bonds, next to 5 USD of accrued yield
+    const supply = 100_000_000;
+    const fiatReserveBalance = 100_000_000;
+    const bondHoldingBalance = 10_000_000;
+    const accruedYield = 5_000_000;
+
+    before(async () => {
+      coin = await createSovereignCoin("USDW", "USD", usdFiatMint, usdBondMint);
+
+      holderCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      holderFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      holderBondAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdBondMint, authority
+      )).address;
+
+      await issueCoins(coin, usdFiatMint, holderCoinAccount, supply);
+
+      // Set a fiat donation aside as yield before the bonds arrive
+      await mintTo(provider.connection, mintAuthority, usdFiatMint, coin.fiatReserve, mintAuthority, accruedYield);
+      await applyFactoryChange({ surplusPolicy: { surplusPolicy: { yield: {} } } });
+      try {
+        await program.methods
+          .syncReserves()
+          .accounts({
+            sovereignCoin: coin.sovereignCoin,
+            factory: factoryPDA,
+            fiatReserve: coin.fiatReserve,
+            bondHolding: coin.bondHolding,
+          })
+          .rpc();
+      } finally {
+        await applyFactoryChange({ surplusPolicy: { surplusPolicy: { protocolOwned: {} } } });
+      }
+      await mintTo(provider.connection, mintAuthority, usdBondMint, coin.bondHolding, mintAuthority, bondHoldingBalance);
+    });
+
+    function settleRedeem(amount: number) {
+      return program.methods
+        .settleRedeem(new anchor.BN(amount))
+        .accounts({
+          holder: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          holderCoinAccount,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          holderFiatAccount,
+          holderBondAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+          bondTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    function closeSovereignCoin() {
+      return program.methods
+        .closeSovereignCoin()
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          treasuryFiatAccount: holderFiatAccount,
+          treasuryBondAccount: holderBondAccount,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+          bondTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    it("Should fail to settle before the wind-down begins", async () => {
+      try {
+        await settleRedeem(1_000_000);
+        expect.fail("Transaction should have failed with not winding down");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("SovereignCoinNotWindingDown");
+      }
+    });
+
+    it("Can begin a wind-down and snapshot the reserves without the yield", async () => {
+      await program.methods
+        .beginWindDown()
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+        })
+        .rpc();
+
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoinAccount.windingDown).to.equal(true);
+      expect(sovereignCoinAccount.paused & PAUSE_MINT).to.equal(PAUSE_MINT);
+      expect(sovereignCoinAccount.windDownSupply.toNumber()).to.equal(supply);
+      expect(sovereignCoinAccount.windDownFiat.toNumber()).to.equal(fiatReserveBalance);
+      expect(sovereignCoinAccount.windDownBond.toNumber()).to.equal(bondHoldingBalance);
+      expect(sovereignCoinAccount.accruedYieldFiat.toNumber()).to.equal(accruedYield);
+    });
+
+    it("Lets the treasurer withdraw accrued yield during a wind-down", async () => {
+      const fiatBefore = await provider.connection.getTokenAccountBalance(holderFiatAccount);
+
+      await program.methods
+        .withdrawAccruedYield(new anchor.BN(accruedYield), new anchor.BN(0))
+        .accounts({
+          treasurer: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          coinRoles: rolesPDA(coin.sovereignCoin),
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          destinationFiatAccount: holderFiatAccount,
+          destinationBondAccount: holderBondAccount,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+          bondTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const fiatAfter = await provider.connection.getTokenAccountBalance(holderFiatAccount);
+      expect(Number(fiatAfter.value.amount) - Number(fiatBefore.value.amount)).to.equal(accruedYield);
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoinAccount.accruedYieldFiat.toNumber()).to.equal(0);
+      expect(sovereignCoinAccount.windDownFiat.toNumber()).to.equal(fiatReserveBalance);
+    });
+
+    it("Should fail to unpause minting during a wind-down", async () => {
+      try {
+        await program.methods
+          .unpauseSovereignCoin(PAUSE_MINT)
+          .accounts({
+            authority: authority,
+            factory: factoryPDA,
+            sovereignCoin: coin.sovereignCoin,
+          })
+          .rpc();
+
+        expect.fail("Transaction should have failed with winding down");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("SovereignCoinWindingDown");
+      }
+    });
+
+    it("Settles a redemption pro-rata against the snapshot", async () => {
+      const fiatBefore = await provider.connection.getTokenAccountBalance(holderFiatAccount);
+      const bondBefore = await provider.connection.getTokenAccountBalance(holderBondAccount);
+
+      await settleRedeem(40_000_000);
+
+      const fiatAfter = await provider.connection.getTokenAccountBalance(holderFiatAccount);
+      const bondAfter = await provider.connection.getTokenAccountBalance(holderBondAccount);
+      expect(Number(fiatAfter.value.amount) - Number(fiatBefore.value.amount)).to.equal(40_000_000);
+      expect(Number(bondAfter.value.amount) - Number(bondBefore.value.amount)).to.equal(4_000_000);
+    });
+
+    it("Should fail to close while supply remains", async () => {
+      try {
+        await closeSovereignCoin();
+        expect.fail("Transaction should have failed with supply not zero");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("SupplyNotZero");
+      }
+    });
+
+    it("Can close the coin once all holders have settled", async () => {
+      await settleRedeem(60_000_000);
+
+      const factoryBefore = await program.account.factory.fetch(factoryPDA);
+      await closeSovereignCoin();
+
+      const closed = await provider.connection.getAccountInfo(coin.sovereignCoin);
+      expect(closed).to.be.null;
+      expect(await provider.connection.getAccountInfo(coin.fiatReserve)).to.be.null;
+      expect(await provider.connection.getAccountInfo(coin.bondHolding)).to.be.null;
+
+      // The coin never ran finalize_setup, so it was never counted
+      const factoryAfter = await program.account.factory.fetch(factoryPDA);
+      expect(factoryAfter.totalSovereignCoins.toNumber()).to.equal(factoryBefore.totalSovereignCoins.toNumber());
+    });
+  });
+
+  describe("interest-bearing", () => {
+    const symbol = "USDI";
+    const [sovereignCoin] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from(symbol)],
+      program.programId
+    );
+    const mint = Keypair.generate();
+    const fiatReserve = Keypair.generate();
+    const bondHolding = Keypair.generate();
+    const supply = 100_000_000;
+    const accruedYield = 5_000_000;
+
+    before(async () => {
+      await program.methods
+        .initSovereignCoin({
+          name: "US Dollar Interest",
+          symbol,
+          uri: "https://example.com/usdi.json",
+          fiatCurrency: "USD",
+        })
+        .accounts({
+          payer: authority,
+          authority: authority,
+          factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
+          sovereignCoin,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      await program.methods
+        .setupToken2022Mint({ transferHook: false, interestBearing: true, permanentDelegate: false })
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin,
+          mint: mint.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([mint])
+        .rpc();
+
+      await program.methods
+        .setupTokenAccounts()
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin,
+          fiatReserve: fiatReserve.publicKey,
+          bondHolding: bondHolding.publicKey,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([fiatReserve, bondHolding])
+        .rpc();
+
+      const holderAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, mint.publicKey, authority, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      );
+      const coin = { sovereignCoin, mint: mint.publicKey, fiatReserve: fiatReserve.publicKey };
+      await issueCoins(coin, usdFiatMint, holderAccount.address, supply, TOKEN_2022_PROGRAM_ID);
+      await mintTo(provider.connection, mintAuthority, usdFiatMint, fiatReserve.publicKey, mintAuthority, accruedYield);
+
+      // Set the donation aside as yield
+      await applyFactoryChange({ surplusPolicy: { surplusPolicy: { yield: {} } } });
+      try {
+        await program.methods
+          .syncReserves()
+          .accounts({
+            sovereignCoin,
+            factory: factoryPDA,
+            fiatReserve: fiatReserve.publicKey,
+            bondHolding: bondHolding.publicKey,
+          })
+          .rpc();
+      } finally {
+        await applyFactoryChange({ surplusPolicy: { surplusPolicy: { protocolOwned: {} } } });
+      }
+    });
+
+    function harvestYield(signer: Keypair = mintAuthority) {
+      return program.methods
+        .harvestYield()
+        .accounts({
+          rateUpdater: signer.publicKey,
+          sovereignCoin,
+          coinRoles: rolesPDA(sovereignCoin),
+          factory: factoryPDA,
+          mint: mint.publicKey,
+          fiatReserve: fiatReserve.publicKey,
+          priceOracle: manualPricePDA(usdBondMint),
+          bondTokenMint: usdBondMint,
+          fiatTokenMint: usdFiatMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("Creates the mint with the coin PDA as rate authority", async () => {
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(sovereignCoin);
+      expect(sovereignCoinAccount.interestBearing).to.equal(true);
+      expect(sovereignCoinAccount.holderRateBps).to.equal(0);
+
+      const mintInfo = await getMint(provider.connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+      const config = getInterestBearingMintConfigState(mintInfo);
+      expect(config.rateAuthority.toString()).to.equal(sovereignCoin.toString());
+      expect(config.currentRate).to.equal(0);
+    });
+
+    it("Should fail to harvest without the rate updater role", async () => {
+      try {
+        await harvestYield(Keypair.generate());
+        expect.fail("Transaction should have failed with unauthorized signer");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Harvests the holder share of accrued yield into the mint's rate", async () => {
+      // Make sure the harvest period is not empty
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await harvestYield();
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      const holderFiat = Math.floor(accruedYield * factory.yieldShareHolders / 100);
+
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(sovereignCoin);
+      expect(sovereignCoinAccount.accruedYieldFiat.toNumber()).to.equal(accruedYield - holderFiat);
+      expect(sovereignCoinAccount.fiatAmount.toNumber()).to.equal(supply + holderFiat);
+      expect(sovereignCoinAccount.holderRateBps).to.be.greaterThan(0);
+
+      const mintInfo = await getMint(provider.connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+      const config = getInterestBearingMintConfigState(mintInfo);
+      expect(config.currentRate).to.equal(sovereignCoinAccount.holderRateBps);
+    });
+
+    it("Reports the interest-scaled supply in the reserve status", async () => {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const status = await program.methods
+        .getReserveStatus()
+        .accounts({
+          sovereignCoin,
+          factory: factoryPDA,
+          mint: mint.publicKey,
+          fiatReserve: fiatReserve.publicKey,
+          bondHolding: bondHolding.publicKey,
+          priceOracle: manualPricePDA(usdBondMint),
+          bondTokenMint: usdBondMint,
+          fiatTokenMint: usdFiatMint,
+          insuranceVault: null,
+        })
+        .view();
+
+      expect(status.totalSupply.toNumber()).to.equal(supply);
+      expect(status.scaledSupply.toNumber()).to.be.greaterThan(supply);
+    });
+
+    it("Charges new coins at their interest-scaled value", async () => {
+      const holderAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, mint.publicKey, authority, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      );
+      const walletFiatAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      );
+      const coin = { sovereignCoin, mint: mint.publicKey, fiatReserve: fiatReserve.publicKey };
+      const reserveBefore = await balance(fiatReserve.publicKey);
+      const before = await program.account.sovereignCoin.fetch(sovereignCoin);
+
+      // issueCoins only funds the raw amount, so cover the interest on top
+      await mintTo(provider.connection, mintAuthority, usdFiatMint, walletFiatAccount.address, mintAuthority, 1_000_000);
+      await issueCoins(coin, usdFiatMint, holderAccount.address, supply, TOKEN_2022_PROGRAM_ID);
+
+      const deposited = await balance(fiatReserve.publicKey) - reserveBefore;
+      expect(deposited).to.be.greaterThan(supply);
+      const after = await program.account.sovereignCoin.fetch(sovereignCoin);
+      expect(after.fiatAmount.toNumber() - before.fiatAmount.toNumber()).to.equal(deposited);
+      expect(after.totalSupply.toNumber() - before.totalSupply.toNumber()).to.equal(supply);
+    });
+
+    it("Should fail to harvest a coin without an interest-bearing mint", async () => {
+      try {
+        await program.methods
+          .harvestYield()
+          .accounts({
+            rateUpdater: authority,
+            sovereignCoin: usdsCoinPDA,
+            coinRoles: rolesPDA(usdsCoinPDA),
+            factory: factoryPDA,
+            mint: usdsMint.publicKey,
+            fiatReserve: usdsFiatReserve.publicKey,
+            priceOracle: manualPricePDA(usdBondMint),
+            bondTokenMint: usdBondMint,
+            fiatTokenMint: usdFiatMint,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc();
+
+        expect.fail("Transaction should have failed with not interest-bearing");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NotInterestBearing");
+      }
+    });
+  });
+
+  describe("freeze and seizure", () => {
+    const symbol = "USDF";
+    const [sovereignCoin] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from(symbol)],
+      program.programId
+    );
+    const mint = Keypair.generate();
+    const fiatReserve = Keypair.generate();
+    const bondHolding = Keypair.generate();
+    const officer = Keypair.generate();
+    const holder = Keypair.generate();
+    let holderAccount: PublicKey;
+    let custodyAccount: PublicKey;
+
+    before(async () => {
+      await program.methods
+        .initSovereignCoin({
+          name: "US Dollar Freezable",
+          symbol,
+          uri: "https://example.com/usdf.json",
+          fiatCurrency: "USD",
+        })
+        .accounts({
+          payer: authority,
+          authority: authority,
+          factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(authority),
+          treasury: authority,
+          sovereignCoin,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      await program.methods
+        .setupToken2022Mint({ transferHook: false, interestBearing: false, permanentDelegate: true })
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin,
+          mint: mint.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([mint])
+        .rpc();
+
+      await program.methods
+        .setupTokenAccounts()
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin,
+          fiatReserve: fiatReserve.publicKey,
+          bondHolding: bondHolding.publicKey,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([fiatReserve, bondHolding])
+        .rpc();
+
+      holderAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, mint.publicKey, holder.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      )).address;
+      custodyAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, mint.publicKey, authority, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      )).address;
+      await issueCoins(
+        { sovereignCoin, mint: mint.publicKey, fiatReserve: fiatReserve.publicKey },
+        usdFiatMint,
+        holderAccount,
+        10_000_000,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await program.methods
+        .grantRole({ complianceOfficer: {} }, officer.publicKey)
+        .accounts({
+          admin: authority,
+          roles: rolesPDA(sovereignCoin),
+        })
+        .rpc();
+    });
+
+    function freezeHolder(signer: Keypair, reason: string) {
+      return program.methods
+        .freezeHolder(reason)
+        .accounts({
+          complianceOfficer: signer.publicKey,
+          sovereignCoin,
+          mint: mint.publicKey,
+          holderTokenAccount: holderAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("Gives the coin PDA the mint and freeze authorities of both mint kinds", async () => {
+      const token2022Mint = await getMint(provider.connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(token2022Mint.mintAuthority.toString()).to.equal(sovereignCoin.toString());
+      expect(token2022Mint.freezeAuthority.toString()).to.equal(sovereignCoin.toString());
+
+      const splMint = await getMint(provider.connection, usdsMint.publicKey);
+      expect(splMint.mintAuthority.toString()).to.equal(usdsCoinPDA.toString());
+      expect(splMint.freezeAuthority.toString()).to.equal(usdsCoinPDA.toString());
+    });
+
+    it("Should fail to freeze without the compliance role", async () => {
+      try {
+        await freezeHolder(mintAuthority, "sanctions list match");
+        expect.fail("Transaction should have failed with unauthorized signer");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Should fail to freeze with an overlong reason", async () => {
+      try {
+        await freezeHolder(officer, "x".repeat(65));
+        expect.fail("Transaction should have failed with reason too long");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("ReasonTooLong");
+      }
+    });
+
+    it("Lets the compliance officer freeze a holder", async () => {
+      await freezeHolder(officer, "sanctions list match");
+
+      const account = await getAccount(provider.connection, holderAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(account.isFrozen).to.equal(true);
+    });
+
+    it("Seizes from a frozen holder and keeps it frozen", async () => {
+      await program.methods
+        .seizeHolder(new anchor.BN(4_000_000), "court order 2026-118")
+        .accounts({
+          complianceOfficer: officer.publicKey,
+          sovereignCoin,
+          mint: mint.publicKey,
+          source: holderAccount,
+          destination: custodyAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([officer])
+        .rpc();
+
+      const source = await getAccount(provider.connection, holderAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+      const destination = await getAccount(provider.connection, custodyAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(Number(source.amount)).to.equal(6_000_000);
+      expect(Number(destination.amount)).to.equal(4_000_000);
+      expect(source.isFrozen).to.equal(true);
+    });
+
+    it("Lets the compliance officer thaw a holder", async () => {
+      await program.methods
+        .thawHolder("delisted")
+        .accounts({
+          complianceOfficer: officer.publicKey,
+          sovereignCoin,
+          mint: mint.publicKey,
+          holderTokenAccount: holderAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([officer])
+        .rpc();
+
+      const account = await getAccount(provider.connection, holderAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(account.isFrozen).to.equal(false);
+    });
+  });
+
+  describe("roles", () => {
+    const oracleUpdater = Keypair.generate();
+    const outsider = Keypair.generate();
+
+    function updateManualPrice(signer: Keypair) {
+      return program.methods
+        .updateManualPrice(new anchor.BN(1_020_000), new anchor.BN(1_000))
+        .accounts({
+          authority: signer.publicKey,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          manualPrice: manualPricePDA(usdBondMint),
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    function withdrawAccruedYield(signer: Keypair, fiatAmount: number, destination: PublicKey, bondDestination: PublicKey) {
+      return program.methods
+        .withdrawAccruedYield(new anchor.BN(fiatAmount), new anchor.BN(0))
+        .accounts({
+          treasurer: signer.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: usdsCoinPDA,
+          coinRoles: rolesPDA(usdsCoinPDA),
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          destinationFiatAccount: destination,
+          destinationBondAccount: bondDestination,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+          bondTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("Gives the creators every role but guardian", async () => {
+      const coinRoles = await program.account.roles.fetch(rolesPDA(usdsCoinPDA));
+      expect(coinRoles.scope.toString()).to.equal(usdsCoinPDA.toString());
+      expect(coinRoles.issuerAdmin.toString()).to.equal(authority.toString());
+      expect(coinRoles.minter.toString()).to.equal(authority.toString());
+      expect(coinRoles.treasurer.toString()).to.equal(authority.toString());
+      expect(coinRoles.guardian.toString()).to.equal(PublicKey.default.toString());
+    });
+
+    it("Should fail to grant a role without the issuer admin role", async () => {
+      try {
+        await program.methods
+          .grantRole({ minter: {} }, outsider.publicKey)
+          .accounts({
+            admin: outsider.publicKey,
+            roles: rolesPDA(factoryPDA),
+          })
+          .signers([outsider])
+          .rpc();
+
+        expect.fail("Transaction should have failed with unauthorized admin");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Should fail to revoke the issuer admin role", async () => {
+      try {
+        await program.methods
+          .revokeRole({ issuerAdmin: {} })
+          .accounts({
+            admin: authority,
+            roles: rolesPDA(factoryPDA),
+          })
+          .rpc();
+
+        expect.fail("Transaction should have failed with cannot revoke issuer admin");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("CannotRevokeIssuerAdmin");
+      }
+    });
+
+    it("Only the rate oracle updater can publish manual prices", async () => {
+      await program.methods
+        .grantRole({ rateOracleUpdater: {} }, oracleUpdater.publicKey)
+        .accounts({
+          admin: authority,
+          roles: rolesPDA(factoryPDA),
+        })
+        .rpc();
+
+      try {
+        await updateManualPrice(mintAuthority);
+        expect.fail("Transaction should have failed with unauthorized updater");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await updateManualPrice(oracleUpdater);
+
+      await program.methods
+        .grantRole({ rateOracleUpdater: {} }, authority)
+        .accounts({
+          admin: authority,
+          roles: rolesPDA(factoryPDA),
+        })
+        .rpc();
+    });
+
+    it("Only the treasurer can withdraw accrued yield", async () => {
+      const destination = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      const bondDestination = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdBondMint, authority
+      )).address;
+      const before = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+      const amount = Math.min(1_000_000, before.accruedYieldFiat.toNumber());
+
+      try {
+        await withdrawAccruedYield(outsider, amount, destination, bondDestination);
+        expect.fail("Transaction should have failed with unauthorized treasurer");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      try {
+        await withdrawAccruedYield(mintAuthority, before.accruedYieldFiat.toNumber() + 1, destination, bondDestination);
+        expect.fail("Transaction should have failed with insufficient accrued yield");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InsufficientAccruedYield");
+      }
+
+      await withdrawAccruedYield(mintAuthority, amount, destination, bondDestination);
+
+      const after = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+      expect(after.accruedYieldFiat.toNumber()).to.equal(before.accruedYieldFiat.toNumber() - amount);
+      expect(after.fiatAmount.toNumber()).to.equal(before.fiatAmount.toNumber());
+    });
+
+    it("Revoked roles are held by nobody", async () => {
+      await program.methods
+        .revokeRole({ minter: {} })
+        .accounts({
+          admin: authority,
+          roles: rolesPDA(usdsCoinPDA),
+        })
+        .rpc();
+
+      const coinRoles = await program.account.roles.fetch(rolesPDA(usdsCoinPDA));
+      expect(coinRoles.minter.toString()).to.equal(PublicKey.default.toString());
+    });
+  });
+
+  describe("timelock", () => {
+    const pendingChangePDA = (id: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_change"), factoryPDA.toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    async function queueChange(change: any): Promise<PublicKey> {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      const pendingChange = pendingChangePDA(factory.nextChangeId);
+      await program.methods
+        .queueFactoryChange(change)
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          pendingChange,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      return pendingChange;
+    }
+
+    function executeChange(pendingChange: PublicKey) {
+      return program.methods
+        .executeFactoryChange()
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          pendingChange,
+          queuedBy: authority,
+        })
+        .rpc();
+    }
+
+    async function setDelay(delay: number) {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      const pendingChange = await queueChange({ timelockDelay: { delay: new anchor.BN(delay) } });
+      await sleep(factory.timelockDelay.toNumber() * 1000 + 1000);
+      await executeChange(pendingChange);
+    }
+
+    it("Starts at the minimum delay", async () => {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.timelockDelay.toNumber()).to.equal(MIN_TIMELOCK_DELAY);
+    });
+
+    it("Should fail to drop the delay below the minimum", async () => {
+      try {
+        await queueChange({ timelockDelay: { delay: new anchor.BN(0) } });
+        expect.fail("Transaction should have failed with invalid timelock delay");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidTimelockDelay");
+      }
+    });
+
+    it("Raises the delay through a timelocked change", async () => {
+      await setDelay(MIN_TIMELOCK_DELAY + 1);
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.timelockDelay.toNumber()).to.equal(MIN_TIMELOCK_DELAY + 1);
+    });
+
+    it("Holds a queued change until the delay elapses", async () => {
+      const pendingChange = await queueChange({ fees: { mintFeeBps: 25, burnFeeBps: 10 } });
+
+      const queued = await program.account.pendingChange.fetch(pendingChange);
+      expect(queued.eta.toNumber() - queued.queuedAt.toNumber()).to.equal(MIN_TIMELOCK_DELAY + 1);
+
+      try {
+        await executeChange(pendingChange);
+        expect.fail("Transaction should have failed with timelock not elapsed");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("TimelockNotElapsed");
+      }
+
+      await sleep((MIN_TIMELOCK_DELAY + 2) * 1000);
+      await executeChange(pendingChange);
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.mintFeeBps).to.equal(25);
+      expect(factory.burnFeeBps).to.equal(10);
+      expect(await provider.connection.getAccountInfo(pendingChange)).to.be.null;
+    });
+
+    it("Should fail to queue an invalid change", async () => {
+      try {
+        await queueChange({ yieldShares: { protocol: 50, issuer: 50, holders: 50 } });
+        expect.fail("Transaction should have failed with invalid yield distribution");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidYieldDistribution");
+      }
+    });
+
+    it("Lets the guardian cancel a queued bond mapping update", async () => {
+      const fiatCurrency = Array.from(Buffer.concat([Buffer.from("EUR"), Buffer.alloc(5)]));
+      const pendingChange = await queueChange({
+        bondMapping: { fiatCurrency, bondMint: eurBondMint, bondRating: 9, active: false },
+      });
+
+      await program.methods
+        .cancelFactoryChange()
+        .accounts({
+          signer: guardian.publicKey,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          pendingChange,
+          queuedBy: authority,
+        })
+        .signers([guardian])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(pendingChange)).to.be.null;
+      const factory = await program.account.factory.fetch(factoryPDA);
+      const eurMapping = factory.bondMappings.find((m) => Buffer.from(m.fiatCurrency).toString().startsWith("EUR"));
+      expect(eurMapping.active).to.equal(1);
+    });
+
+    it("Should fail to re-register an existing bond mapping directly", async () => {
+      try {
+        await program.methods
+          .registerBondMaps("USD", usdBondMint, 1)
+          .accounts({
+            authority: authority,
+            factory: factoryPDA,
+            factoryRoles: rolesPDA(factoryPDA),
+          })
+          .rpc();
+
+        expect.fail("Transaction should have failed with bond mapping exists");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("BondMappingExists");
+      }
+    });
+
+    after(async () => {
+      // Leave the factory as the other suites expect it
+      await setDelay(MIN_TIMELOCK_DELAY);
+      await applyFactoryChange({ fees: { mintFeeBps: 0, burnFeeBps: 0 } });
+    });
+  });
+
+  describe("coin limits", () => {
+    const U64_MAX = new anchor.BN("18446744073709551615");
+
+    function setCoinLimits(maxSupply: anchor.BN, mintLimit: anchor.BN, redeemLimit: anchor.BN, signer: Keypair = mintAuthority) {
+      return program.methods
+        .setCoinLimits(maxSupply, mintLimit, redeemLimit)
+        .accounts({
+          authority: signer.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: usdsCoinPDA,
+          coinRoles: rolesPDA(usdsCoinPDA),
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    async function setCoinLimitBounds(maxSupply: anchor.BN, mintLimit: anchor.BN, redeemLimit: anchor.BN) {
+      await applyFactoryChange({ coinLimitBounds: { maxSupply, mintLimit, redeemLimit } });
+    }
+
+    it("Starts coins at the factory bounds", async () => {
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+      expect(sovereignCoinAccount.maxSupply.toString()).to.equal(U64_MAX.toString());
+      expect(sovereignCoinAccount.mintLimit.limit.toString()).to.equal(U64_MAX.toString());
+      expect(sovereignCoinAccount.redeemLimit.limit.toString()).to.equal(U64_MAX.toString());
+    });
+
+    it("Lets the issuer admin tighten the limits", async () => {
+      await setCoinLimits(new anchor.BN(1_000_000_000_000), new anchor.BN(50_000_000_000), new anchor.BN(20_000_000_000));
+
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+      expect(sovereignCoinAccount.maxSupply.toNumber()).to.equal(1_000_000_000_000);
+      expect(sovereignCoinAccount.mintLimit.limit.toNumber()).to.equal(50_000_000_000);
+      expect(sovereignCoinAccount.redeemLimit.limit.toNumber()).to.equal(20_000_000_000);
+    });
+
+    it("Should fail to set limits without the issuer admin role", async () => {
+      const outsider = Keypair.generate();
+      try {
+        await setCoinLimits(new anchor.BN(1), new anchor.BN(1), new anchor.BN(1), outsider);
+        expect.fail("Transaction should have failed with unauthorized signer");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Should fail to raise limits above the factory bounds", async () => {
+      await setCoinLimitBounds(new anchor.BN(2_000_000_000_000), new anchor.BN(100_000_000_000), new anchor.BN(100_000_000_000));
+
+      try {
+        await setCoinLimits(new anchor.BN(3_000_000_000_000), new anchor.BN(50_000_000_000), new anchor.BN(20_000_000_000));
+        expect.fail("Transaction should have failed with limit above factory bound");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("LimitAboveFactoryBound");
+      } finally {
+        await setCoinLimitBounds(U64_MAX, U64_MAX, U64_MAX);
+      }
+    });
+
+    it("Holds existing coins to lowered factory bounds", async () => {
+      const coin = await createSovereignCoin("USDL", "USD", usdFiatMint, usdBondMint);
+      const holderCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      await setCoinLimitBounds(new anchor.BN(5_000_000), U64_MAX, U64_MAX);
+
+      try {
+        await issueCoins(coin, usdFiatMint, holderCoinAccount, 1_000_000);
+        const sovereignCoinAccount = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+        expect(sovereignCoinAccount.maxSupply.toNumber()).to.equal(5_000_000);
+
+        try {
+          await issueCoins(coin, usdFiatMint, holderCoinAccount, 5_000_000);
+          expect.fail("Transaction should have failed with max supply exceeded");
+        } catch (err) {
+          expect(err.error.errorCode.code).to.equal("MaxSupplyExceeded");
+        }
+      } finally {
+        await setCoinLimitBounds(U64_MAX, U64_MAX, U64_MAX);
+      }
+    });
+  });
+
+  describe("circuit breaker", () => {
+    const U64_MAX = new anchor.BN("18446744073709551615");
+    const PAUSE_ALL = 0b1111;
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let holderCoinAccount: PublicKey;
+    let holderFiatAccount: PublicKey;
+    let holderBondAccount: PublicKey;
+
+    before(async () => {
+      coin = await createSovereignCoin("USDB", "USD", usdFiatMint, usdBondMint);
+
+      holderCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      holderFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      holderBondAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdBondMint, authority
+      )).address;
+
+      await issueCoins(coin, usdFiatMint, holderCoinAccount, 30_000_000);
+
+      // Settlement is the one redemption path, so run it on a wound-down coin
+      await program.methods
+        .beginWindDown()
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+        })
+        .rpc();
+    });
+
+    async function setBreaker(outflowLimit: anchor.BN, maxPriceDropBps: number, breakerFlags: number) {
+      await applyFactoryChange({ circuitBreaker: { outflowLimit, maxPriceDropBps, breakerFlags } });
+    }
+
+    function settleRedeem(amount: number) {
+      return program.methods
+        .settleRedeem(new anchor.BN(amount))
+        .accounts({
+          holder: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          holderCoinAccount,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          holderFiatAccount,
+          holderBondAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+          bondTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    function refreshReserveNav() {
+      return program.methods
+        .refreshReserveNav()
+        .accounts({
+          sovereignCoin: usdsCoinPDA,
+          factory: factoryPDA,
+          priceOracle: manualPricePDA(usdBondMint),
+          bondTokenMint: usdBondMint,
+          fiatReserve: usdsFiatReserve.publicKey,
+          bondHolding: usdsBondHolding.publicKey,
+          fiatTokenMint: usdFiatMint,
+        })
+        .rpc();
+    }
+
+    function updateManualPrice(price: number) {
+      return program.methods
+        .updateManualPrice(new anchor.BN(price), new anchor.BN(1_000))
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          manualPrice: manualPricePDA(usdBondMint),
+        })
+        .rpc();
+    }
+
+    function resetCircuitBreaker(signer: Keypair) {
+      return program.methods
+        .resetCircuitBreaker()
+        .accounts({
+          guardian: signer.publicKey,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("Starts with the breaker disabled", async () => {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.outflowLimit.toString()).to.equal(U64_MAX.toString());
+      expect(factory.maxPriceDropBps).to.equal(0);
+      expect(factory.breakerFlags).to.equal(PAUSE_ALL);
+      expect(factory.breakerTripKind).to.equal(0);
+    });
+
+    it("Should fail to configure the breaker with invalid pause flags", async () => {
+      try {
+        await setBreaker(U64_MAX, 0, 0);
+        expect.fail("Transaction should have failed with invalid pause flags");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidPauseFlags");
+      }
+    });
+
+    it("Trips into a full pause once outflows exceed the window's limit", async () => {
+      await setBreaker(new anchor.BN(10_000_000), 0, PAUSE_ALL);
+
+      await settleRedeem(6_000_000);
+      let factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.breakerTripKind).to.equal(0);
+
+      // The crossing settlement still goes through and trips the breaker
+      await settleRedeem(6_000_000);
+      factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.breakerTripKind).to.equal(1);
+      expect(factory.paused).to.equal(PAUSE_ALL);
+      expect(factory.breakerTrippedAt.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await settleRedeem(1_000_000);
+        expect.fail("Transaction should have failed with factory paused");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("FactoryPaused");
+      }
+    });
+
+    it("Should fail to reset the breaker without the guardian", async () => {
+      try {
+        await resetCircuitBreaker(mintAuthority);
+        expect.fail("Transaction should have failed with unauthorized signer");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Lets the guardian reset the breaker", async () => {
+      await resetCircuitBreaker(guardian);
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.breakerTripKind).to.equal(0);
+      expect(factory.paused).to.equal(0);
+      for (const mapping of factory.bondMappings) {
+        expect(mapping.outflowUsed.toNumber()).to.equal(0);
+      }
+
+      await settleRedeem(1_000_000);
+
+      try {
+        await resetCircuitBreaker(guardian);
+        expect.fail("Transaction should have failed with breaker not tripped");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("BreakerNotTripped");
+      }
+    });
+
+    it("Trips into redeem-only when the bond price drops too far", async () => {
+      await setBreaker(U64_MAX, 500, PAUSE_MINT);
+
+      try {
+        // 1.02 becomes the window's reference, then a 6.9% drop
+        await refreshReserveNav();
+        await updateManualPrice(950_000);
+        await refreshReserveNav();
+
+        const factory = await program.account.factory.fetch(factoryPDA);
+        expect(factory.breakerTripKind).to.equal(2);
+        expect(Buffer.from(factory.breakerTripCurrency).toString().replace(/\0/g, "")).to.equal("USD");
+        expect(factory.breakerTripDropBps).to.equal(686);
+        expect(factory.paused).to.equal(PAUSE_MINT);
+
+        // Holders can still redeem
+        await settleRedeem(1_000_000);
+      } finally {
+        await updateManualPrice(1_020_000);
+        await resetCircuitBreaker(guardian);
+        await setBreaker(U64_MAX, 0, PAUSE_ALL);
+      }
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.paused).to.equal(0);
+    });
+  });
+
+  describe("coin fees", () => {
+    const marketMakerWallet = Keypair.generate();
+    const marketMakerPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("market_maker"), usdsCoinPDA.toBuffer(), marketMakerWallet.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+    async function setIssuerFeeBound(maxIssuerFeeBps: number) {
+      await applyFactoryChange({ issuerFeeBound: { maxIssuerFeeBps } });
+    }
+
+    function setCoinFees(tiers: { minAmount: anchor.BN; mintFeeBps: number; redeemFeeBps: number }[]) {
+      return program.methods
+        .setCoinFees(tiers)
+        .accounts({
+          authority: mintAuthority.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: usdsCoinPDA,
+          coinRoles: rolesPDA(usdsCoinPDA),
+        })
+        .signers([mintAuthority])
+        .rpc();
+    }
+
+    function quoteFees(operation: any, amount: number, wallet: PublicKey, marketMaker: PublicKey | null = null) {
+      return program.methods
+        .quoteFees(operation, new anchor.BN(amount))
+        .accounts({
+          factory: factoryPDA,
+          sovereignCoin: usdsCoinPDA,
+          wallet,
+          marketMaker,
+        })
+        .view();
+    }
+
+    const tiers = [
+      { minAmount: new anchor.BN(0), mintFeeBps: 30, redeemFeeBps: 20 },
+      { minAmount: new anchor.BN(1_000_000_000), mintFeeBps: 10, redeemFeeBps: 5 },
+    ];
+
+    after(async () => {
+      await setCoinFees([]);
+      await setIssuerFeeBound(0);
+    });
+
+    it("Should fail to set issuer fees above the factory bound", async () => {
+      try {
+        await setCoinFees(tiers);
+        expect.fail("Transaction should have failed with fee above factory bound");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("FeeAboveFactoryBound");
+      }
+    });
+
+    it("Lets the issuer admin set fee tiers within the bound", async () => {
+      await setIssuerFeeBound(50);
+      await setCoinFees(tiers);
+
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(usdsCoinPDA);
+      expect(sovereignCoinAccount.feeSchedule.tiersCount).to.equal(2);
+      expect(sovereignCoinAccount.feeSchedule.tiers[1].minAmount.toNumber()).to.equal(1_000_000_000);
+      expect(sovereignCoinAccount.feeSchedule.tiers[1].mintFeeBps).to.equal(10);
+    });
+
+    it("Should fail to set tiers that do not start at zero", async () => {
+      try {
+        await setCoinFees([{ minAmount: new anchor.BN(1), mintFeeBps: 10, redeemFeeBps: 10 }]);
+        expect.fail("Transaction should have failed with invalid fee tiers");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidFeeTiers");
+      }
+    });
+
+    it("Quotes the issuer fee from the tier the amount reaches", async () => {
+      const small = await quoteFees({ mint: {} }, 100_000_000, authority);
+      expect(small.issuerFeeBps).to.equal(30);
+      expect(small.issuerFee.toNumber()).to.equal(300_000);
+      expect(small.protocolFee.toNumber()).to.equal(0);
+      expect(small.netAmount.toNumber()).to.equal(99_700_000);
+
+      const large = await quoteFees({ redeem: {} }, 2_000_000_000, authority);
+      expect(large.issuerFeeBps).to.equal(5);
+      expect(large.issuerFee.toNumber()).to.equal(1_000_000);
+    });
+
+    it("Charges market makers neither fee", async () => {
+      await program.methods
+        .addMarketMaker(marketMakerWallet.publicKey)
+        .accounts({
+          payer: authority,
+          authority: mintAuthority.publicKey,
+          sovereignCoin: usdsCoinPDA,
+          coinRoles: rolesPDA(usdsCoinPDA),
+          marketMaker: marketMakerPDA,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([mintAuthority])
+        .rpc();
+
+      const previousFees = await program.account.factory.fetch(factoryPDA);
+      await applyFactoryChange({ fees: { mintFeeBps: 25, burnFeeBps: 10 } });
+      try {
+        const quote = await quoteFees({ mint: {} }, 100_000_000, marketMakerWallet.publicKey, marketMakerPDA);
+        expect(quote.protocolFee.toNumber()).to.equal(0);
+        expect(quote.issuerFee.toNumber()).to.equal(0);
+        expect(quote.netAmount.toNumber()).to.equal(100_000_000);
+
+        const walletQuote = await quoteFees({ mint: {} }, 100_000_000, authority);
+        expect(walletQuote.protocolFee.toNumber()).to.equal(250_000);
+      } finally {
+        await applyFactoryChange({
+          fees: { mintFeeBps: previousFees.mintFeeBps, burnFeeBps: previousFees.burnFeeBps },
+        });
+      }
+
+      await program.methods
+        .removeMarketMaker(marketMakerWallet.publicKey)
+        .accounts({
+          rentReceiver: authority,
+          authority: mintAuthority.publicKey,
+          sovereignCoin: usdsCoinPDA,
+          coinRoles: rolesPDA(usdsCoinPDA),
+          marketMaker: marketMakerPDA,
+        })
+        .signers([mintAuthority])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(marketMakerPDA)).to.be.null;
+    });
+
+    it("Should fail to raise the protocol fee past the issuer fee headroom", async () => {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      const [pendingChange] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_change"), factoryPDA.toBuffer(), factory.nextChangeId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      try {
+        await program.methods
+          .queueFactoryChange({ fees: { mintFeeBps: 9_990, burnFeeBps: 0 } })
+          .accounts({ authority: authority, factory: factoryPDA, pendingChange })
+          .rpc();
+        expect.fail("Transaction should have failed with invalid basis points");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+    });
+  });
+
+  describe("flash mint", () => {
+    const borrower = Keypair.generate();
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let borrowerCoinAccount: PublicKey;
+    let treasuryCoinAccount: PublicKey;
+
+    before(async () => {
+      coin = await createSovereignCoin("USDL", "USD", usdFiatMint, usdBondMint);
+
+      const sig = await provider.connection.requestAirdrop(borrower.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+
+      borrowerCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, borrower.publicKey
+      )).address;
+      treasuryCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+
+      // Enough to cover the fee
+      await issueCoins(coin, usdFiatMint, borrowerCoinAccount, 1_000_000);
+    });
+
+    function flashMintIx(amount: number) {
+      return program.methods
+        .flashMint(new anchor.BN(amount))
+        .accounts({
+          borrower: borrower.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          receiver: borrowerCoinAccount,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+    }
+
+    function flashRepayIx() {
+      return program.methods
+        .flashRepay()
+        .accounts({
+          payer: borrower.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          payerCoinAccount: borrowerCoinAccount,
+          treasuryCoinAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+    }
+
+    async function sendFlash(instructions: anchor.web3.TransactionInstruction[]) {
+      await provider.sendAndConfirm(new Transaction().add(...instructions), [borrower]);
+    }
+
+    // Errors from sendAndConfirm carry the program logs rather than a parsed error
+    function expectLogError(err: any, code: string) {
+      const logs: string[] = err.logs ?? [];
+      expect(logs.join("\n") + err.toString()).to.include(code);
+    }
+
+    it("Should fail to flash mint while disabled by default", async () => {
+      try {
+        await sendFlash([await flashMintIx(10_000_000), await flashRepayIx()]);
+        expect.fail("Transaction should have failed with flash mint disabled");
+      } catch (err) {
+        expectLogError(err, "FlashMintDisabled");
+      }
+    });
+
+    it("Lets the issuer admin enable flash mints with a cap and fee", async () => {
+      await program.methods
+        .setFlashMintConfig(new anchor.BN(50_000_000), 30)
+        .accounts({
+          authority: authority,
+          sovereignCoin: coin.sovereignCoin,
+          coinRoles: rolesPDA(coin.sovereignCoin),
+        })
+        .rpc();
+
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoinAccount.flashMintCap.toNumber()).to.equal(50_000_000);
+      expect(sovereignCoinAccount.flashFeeBps).to.equal(30);
+    });
+
+    it("Should fail to flash mint without a matching repay", async () => {
+      try {
+        await sendFlash([await flashMintIx(10_000_000)]);
+        expect.fail("Transaction should have failed with flash repay missing");
+      } catch (err) {
+        expectLogError(err, "FlashRepayMissing");
+      }
+    });
+
+    it("Should fail to flash mint above the cap", async () => {
+      try {
+        await sendFlash([await flashMintIx(60_000_000), await flashRepayIx()]);
+        expect.fail("Transaction should have failed with cap exceeded");
+      } catch (err) {
+        expectLogError(err, "FlashMintCapExceeded");
+      }
+    });
+
+    it("Mints and burns within one transaction, charging the fee", async () => {
+      const supplyBefore = (await getMint(provider.connection, coin.mint)).supply;
+
+      await sendFlash([await flashMintIx(50_000_000), await flashRepayIx()]);
+
+      const mintAccount = await getMint(provider.connection, coin.mint);
+      expect(mintAccount.supply.toString()).to.equal(supplyBefore.toString());
+
+      // 0.3% of 50 coins, rounded up
+      expect(await balance(borrowerCoinAccount)).to.equal(1_000_000 - 150_000);
+      expect(await balance(treasuryCoinAccount)).to.equal(150_000);
+
+      const sovereignCoinAccount = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoinAccount.flashOutstanding.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("cross-currency swap", () => {
+    const [fxPoolPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fx_pool"), factoryPDA.toBuffer()],
+      program.programId
+    );
+    const [fxRatePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fx_rate"), Buffer.from("EUR"), Buffer.from("USD")],
+      program.programId
+    );
+    let usdCoin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let eurCoin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let userUsdCoin: PublicKey;
+    let userEurCoin: PublicKey;
+    let poolUsdFiat: PublicKey;
+    let poolEurFiat: PublicKey;
+
+    before(async () => {
+      usdCoin = await createSovereignCoin("USDX", "USD", usdFiatMint, usdBondMint);
+      eurCoin = await createSovereignCoin("EURX", "EUR", eurFiatMint, await getRegisteredBondMint("EUR"));
+
+      userUsdCoin = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdCoin.mint, authority
+      )).address;
+      userEurCoin = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, eurCoin.mint, authority
+      )).address;
+      poolUsdFiat = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, fxPoolPDA, true
+      )).address;
+      poolEurFiat = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, eurFiatMint, fxPoolPDA, true
+      )).address;
+
+      await issueCoins(usdCoin, usdFiatMint, userUsdCoin, 100_000_000);
+      await mintTo(provider.connection, mintAuthority, eurFiatMint, poolEurFiat, mintAuthority, 100_000_000);
+
+      // 1 EUR = 1.10 USD
+      await program.methods
+        .initFxRate("EUR", "USD", -6, 3600, 100)
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          fxRate: fxRatePDA,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .updateFxRate(new anchor.BN(1_100_000), new anchor.BN(1_000))
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          fxRate: fxRatePDA,
+        })
+        .rpc();
+    });
+
+    async function setSwapFee(swapFeeBps: number) {
+      await applyFactoryChange({ swapFee: { swapFeeBps } });
+    }
+
+    function swapUsdForEur(amountIn: number, minAmountOut: number, allowlistEntryOut: PublicKey | null = null) {
+      return program.methods
+        .swapSovereignCoins(new anchor.BN(amountIn), new anchor.BN(minAmountOut))
+        .accounts({
+          user: authority,
+          factory: factoryPDA,
+          fxRate: fxRatePDA,
+          fxPool: fxPoolPDA,
+          sovereignCoinIn: usdCoin.sovereignCoin,
+          sovereignCoinOut: eurCoin.sovereignCoin,
+          mintIn: usdCoin.mint,
+          mintOut: eurCoin.mint,
+          userCoinIn: userUsdCoin,
+          userCoinOut: userEurCoin,
+          fiatReserveIn: usdCoin.fiatReserve,
+          fiatReserveOut: eurCoin.fiatReserve,
+          fiatMintIn: usdFiatMint,
+          fiatMintOut: eurFiatMint,
+          poolFiatIn: poolUsdFiat,
+          poolFiatOut: poolEurFiat,
+          allowlistEntryIn: null,
+          allowlistEntryOut,
+          coinInTokenProgram: TOKEN_PROGRAM_ID,
+          coinOutTokenProgram: TOKEN_PROGRAM_ID,
+          fiatInTokenProgram: TOKEN_PROGRAM_ID,
+          fiatOutTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    it("Swaps at the inverse of the quoted rate and moves reserves through the pool", async () => {
+      await swapUsdForEur(11_000_000, 10_000_000);
+
+      expect(await balance(userUsdCoin)).to.equal(89_000_000);
+      expect(await balance(userEurCoin)).to.equal(10_000_000);
+      expect(await balance(usdCoin.fiatReserve)).to.equal(89_000_000);
+      expect(await balance(poolUsdFiat)).to.equal(11_000_000);
+      expect(await balance(eurCoin.fiatReserve)).to.equal(10_000_000);
+      expect(await balance(poolEurFiat)).to.equal(90_000_000);
+
+      const eurCoinAccount = await program.account.sovereignCoin.fetch(eurCoin.sovereignCoin);
+      expect(eurCoinAccount.totalSupply.toNumber()).to.equal(10_000_000);
+      expect(eurCoinAccount.fiatAmount.toNumber()).to.equal(10_000_000);
+    });
+
+    it("Should fail to swap below the minimum amount out", async () => {
+      try {
+        await swapUsdForEur(11_000_000, 10_000_001);
+        expect.fail("Transaction should have failed with slippage exceeded");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("SlippageExceeded");
+      }
+    });
+
+    it("Charges the swap fee in the minted coin and leaves it in the pool", async () => {
+      await setSwapFee(30);
+      try {
+        await swapUsdForEur(11_000_000, 0);
+      } finally {
+        await setSwapFee(0);
+      }
+
+      // 0.3% of 10 EURX
+      expect(await balance(userEurCoin)).to.equal(10_000_000 + 9_970_000);
+      expect(await balance(poolEurFiat)).to.equal(90_000_000 - 9_970_000);
+    });
+
+    it("Lets the treasurer withdraw FX pool liquidity", async () => {
+      const destination = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      const before = await balance(destination);
+      const withdraw = () => program.methods
+        .withdrawFxPool(new anchor.BN(22_000_000))
+        .accounts({
+          treasurer: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          fxPool: fxPoolPDA,
+          poolAccount: poolUsdFiat,
+          fiatTokenMint: usdFiatMint,
+          destination,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      // Liquidity stays put while redemptions are paused
+      await program.methods
+        .pauseFactory(PAUSE_REDEEM)
+        .accounts({ guardian: guardian.publicKey, factory: factoryPDA })
+        .signers([guardian])
+        .rpc();
+      try {
+        await withdraw();
+        expect.fail("Transaction should have failed with factory paused");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("FactoryPaused");
+      } finally {
+        await program.methods
+          .unpauseFactory(PAUSE_REDEEM)
+          .accounts({ authority: authority, factory: factoryPDA })
+          .rpc();
+      }
+
+      await withdraw();
+      expect(await balance(destination) - before).to.equal(22_000_000);
+      expect(await balance(poolUsdFiat)).to.equal(0);
+    });
+
+    it("Requires an allowlist entry to swap into a restricted coin", async () => {
+      const [allowlistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("primary_market_allowlist"), eurCoin.sovereignCoin.toBuffer(), authority.toBuffer()],
+        program.programId
+      );
+      const setPermissionless = (permissionless: boolean) =>
+        program.methods
+          .setPermissionless(permissionless)
+          .accounts({
+            authority: authority,
+            sovereignCoin: eurCoin.sovereignCoin,
+            coinRoles: rolesPDA(eurCoin.sovereignCoin),
+          })
+          .rpc();
+
+      await setPermissionless(false);
+      try {
+        await swapUsdForEur(11_000_000, 0);
+        expect.fail("Transaction should have failed with not allowlisted");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NotAllowlisted");
+      }
+
+      await program.methods
+        .addToAllowlist(authority)
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin: eurCoin.sovereignCoin,
+          coinRoles: rolesPDA(eurCoin.sovereignCoin),
+          allowlistEntry,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      const before = await balance(userEurCoin);
+      await swapUsdForEur(11_000_000, 0, allowlistEntry);
+      expect(await balance(userEurCoin) - before).to.equal(10_000_000);
+
+      await program.methods
+        .removeFromAllowlist(authority)
+        .accounts({
+          rentReceiver: authority,
+          authority: authority,
+          sovereignCoin: eurCoin.sovereignCoin,
+          coinRoles: rolesPDA(eurCoin.sovereignCoin),
+          allowlistEntry,
+        })
+        .rpc();
+      await setPermissionless(true);
+    });
+
+    it("Counts the burned coins towards the outflow breaker", async () => {
+      const U64_MAX = new anchor.BN("18446744073709551615");
+      await applyFactoryChange({
+        circuitBreaker: { outflowLimit: new anchor.BN(10_000_000), maxPriceDropBps: 0, breakerFlags: PAUSE_MINT },
+      });
+      try {
+        // The crossing swap still goes through and trips the breaker
+        await swapUsdForEur(11_000_000, 0);
+
+        const factory = await program.account.factory.fetch(factoryPDA);
+        expect(factory.breakerTripKind).to.equal(1);
+        expect(factory.paused).to.equal(PAUSE_MINT);
+      } finally {
+        await program.methods
+          .resetCircuitBreaker()
+          .accounts({
+            guardian: guardian.publicKey,
+            factory: factoryPDA,
+            factoryRoles: rolesPDA(factoryPDA),
+          })
+          .signers([guardian])
+          .rpc();
+        await applyFactoryChange({ circuitBreaker: { outflowLimit: U64_MAX, maxPriceDropBps: 0, breakerFlags: 0b1111 } });
+      }
+    });
+  });
+
+  describe("redemption queue", () => {
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let holderCoinAccount: PublicKey;
+    let holderFiatAccount: PublicKey;
+    let redemptionEscrow: PublicKey;
+
+    before(async () => {
+      coin = await createSovereignCoin("USDQ", "USD", usdFiatMint, usdBondMint);
+
+      holderCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      holderFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      [redemptionEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_escrow"), coin.sovereignCoin.toBuffer()],
+        program.programId
+      );
+
+      await issueCoins(coin, usdFiatMint, holderCoinAccount, 30_000_000);
+    });
+
+    function ticketPDA(id: number) {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_ticket"), coin.sovereignCoin.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    }
+
+    function requestRedemption(id: number, amount: number) {
+      return program.methods
+        .requestRedemption(new anchor.BN(amount))
+        .accounts({
+          holder: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          holderCoinAccount,
+          redemptionEscrow,
+          fiatReserve: coin.fiatReserve,
+          fiatDestination: holderFiatAccount,
+          ticket: ticketPDA(id),
+          marketMaker: null,
+          allowlistEntry: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    function settleRedemption(id: number) {
+      // The holder is also the treasury and the issuer here
+      return program.methods
+        .settleRedemption()
+        .accounts({
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          ticket: ticketPDA(id),
+          owner: authority,
+          redemptionEscrow,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          fiatDestination: holderFiatAccount,
+          treasuryFiatAccount: holderFiatAccount,
+          issuerFiatAccount: holderFiatAccount,
+          insuranceVault: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    // Queues behind ticket `id` when the reserve cannot pay straight away
+    function redeemSovereignCoin(amount: number, id: number | null) {
+      return program.methods
+        .redeemSovereignCoin(new anchor.BN(amount))
+        .accounts({
+          holder: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          holderCoinAccount,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          fiatDestination: holderFiatAccount,
+          treasuryFiatAccount: holderFiatAccount,
+          issuerFiatAccount: holderFiatAccount,
+          insuranceVault: null,
+          redemptionEscrow: id === null ? null : redemptionEscrow,
+          ticket: id === null ? null : ticketPDA(id),
+          marketMaker: null,
+          allowlistEntry: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    it("Escrows coins behind tickets in queue order", async () => {
+      for (let id = 0; id < 3; id++) {
+        await requestRedemption(id, 10_000_000);
+      }
+
+      expect(await balance(holderCoinAccount)).to.equal(0);
+      expect(await balance(redemptionEscrow)).to.equal(30_000_000);
+
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.nextTicketId.toNumber()).to.equal(3);
+      expect(sovereignCoin.nextSettleTicketId.toNumber()).to.equal(0);
+      expect(sovereignCoin.queuedRedemptions.toNumber()).to.equal(30_000_000);
+
+      const ticket = await program.account.redemptionTicket.fetch(ticketPDA(1));
+      expect(ticket.id.toNumber()).to.equal(1);
+      expect(ticket.owner.toString()).to.equal(authority.toString());
+      expect(ticket.amount.toNumber()).to.equal(10_000_000);
+      expect(ticket.status).to.deep.equal({ queued: {} });
+    });
+
+    it("Should fail to settle a ticket out of order", async () => {
+      try {
+        await settleRedemption(1);
+        expect.fail("Transaction should have failed with ticket not at front");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("TicketNotAtFront");
+      }
+    });
+
+    it("Settles the front ticket at par from the fiat reserve", async () => {
+      const fiatBefore = await balance(holderFiatAccount);
+      await settleRedemption(0);
+
+      expect(await balance(redemptionEscrow)).to.equal(20_000_000);
+      expect(await balance(coin.fiatReserve)).to.equal(20_000_000);
+      expect(await balance(holderFiatAccount) - fiatBefore).to.equal(10_000_000);
+      expect(await program.account.redemptionTicket.fetchNullable(ticketPDA(0))).to.be.null;
+
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.nextSettleTicketId.toNumber()).to.equal(1);
+      expect(sovereignCoin.queuedRedemptions.toNumber()).to.equal(20_000_000);
+    });
+
+    it("Lets the owner cancel a ticket behind the front", async () => {
+      await program.methods
+        .cancelRedemption()
+        .accounts({
+          owner: authority,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          ticket: ticketPDA(2),
+          redemptionEscrow,
+          ownerCoinAccount: holderCoinAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      expect(await balance(holderCoinAccount)).to.equal(10_000_000);
+      expect(await balance(redemptionEscrow)).to.equal(10_000_000);
+
+      // Stays in the queue until the crank reaches it
+      const ticket = await program.account.redemptionTicket.fetch(ticketPDA(2));
+      expect(ticket.status).to.deep.equal({ cancelled: {} });
+    });
+
+    it("Settles the next ticket once the front one is gone", async () => {
+      await settleRedemption(1);
+
+      expect(await balance(redemptionEscrow)).to.equal(0);
+      expect(await balance(coin.fiatReserve)).to.equal(10_000_000);
+    });
+
+    it("Closes cancelled tickets when the crank reaches them", async () => {
+      await settleRedemption(2);
+
+      expect(await program.account.redemptionTicket.fetchNullable(ticketPDA(2))).to.be.null;
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.nextSettleTicketId.toNumber()).to.equal(3);
+      expect(sovereignCoin.queuedRedemptions.toNumber()).to.equal(0);
+    });
+
+    it("Redeems straight from the reserve while it can pay", async () => {
+      const fiatBefore = await balance(holderFiatAccount);
+      await redeemSovereignCoin(4_000_000, null);
+
+      expect(await balance(holderCoinAccount)).to.equal(6_000_000);
+      expect(await balance(holderFiatAccount) - fiatBefore).to.equal(4_000_000);
+      expect(await balance(coin.fiatReserve)).to.equal(6_000_000);
+
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.nextTicketId.toNumber()).to.equal(3);
+      expect(sovereignCoin.totalSupply.toNumber()).to.equal(6_000_000);
+    });
+
+    it("Closes a ticket passed for a redemption that settles straight away", async () => {
+      await redeemSovereignCoin(1_000_000, 3);
+
+      expect(await balance(holderCoinAccount)).to.equal(5_000_000);
+      expect(await program.account.redemptionTicket.fetchNullable(ticketPDA(3))).to.be.null;
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.nextTicketId.toNumber()).to.equal(3);
+    });
+
+    it("Queues a redemption behind tickets already waiting", async () => {
+      await requestRedemption(3, 1_000_000);
+
+      try {
+        await redeemSovereignCoin(2_000_000, null);
+        expect.fail("Transaction should have failed with ticket required");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("RedemptionTicketRequired");
+      }
+
+      await redeemSovereignCoin(2_000_000, 4);
+      const ticket = await program.account.redemptionTicket.fetch(ticketPDA(4));
+      expect(ticket.amount.toNumber()).to.equal(2_000_000);
+      expect(ticket.status).to.deep.equal({ queued: {} });
+      expect(await balance(redemptionEscrow)).to.equal(3_000_000);
+
+      await settleRedemption(3);
+      await settleRedemption(4);
+      expect(await balance(holderCoinAccount)).to.equal(2_000_000);
+      expect(await balance(coin.fiatReserve)).to.equal(2_000_000);
+    });
+
+    it("Should fail to settle at par once the coin is winding down", async () => {
+      await requestRedemption(5, 1_000_000);
+      await program.methods
+        .beginWindDown()
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+        })
+        .rpc();
+
+      try {
+        await settleRedemption(5);
+        expect.fail("Transaction should have failed with coin winding down");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("SovereignCoinWindingDown");
+      }
+
+      // The holder takes the coins back to redeem pro rata
+      await program.methods
+        .cancelRedemption()
+        .accounts({
+          owner: authority,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          ticket: ticketPDA(5),
+          redemptionEscrow,
+          ownerCoinAccount: holderCoinAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      await settleRedemption(5);
+
+      expect(await balance(holderCoinAccount)).to.equal(2_000_000);
+      expect(await program.account.redemptionTicket.fetchNullable(ticketPDA(5))).to.be.null;
+    });
+  });
+
+  describe("bond rollover", () => {
+    const STABLEBOND_PROGRAM_ID = new PublicKey("BondyhA24H696Y1HudTyBGzZH58PMPCeAoSinHdWMa1f");
+    const fiatCurrency = Array.from(Buffer.concat([Buffer.from("CHF"), Buffer.alloc(5)]));
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let chfFiatMint: PublicKey;
+    let chfBondMint: PublicKey;
+    let newBondMint: PublicKey;
+
+    before(async () => {
+      chfFiatMint = await createMint(provider.connection, mintAuthority, authority, null, 6);
+      chfBondMint = await createMint(provider.connection, mintAuthority, authority, null, 6);
+      newBondMint = await createMint(
+        provider.connection, mintAuthority, authority, null, 6, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      );
+      await program.methods
+        .registerBondMaps("CHF", chfBondMint, 1)
+        .accounts({ authority: authority, factory: factoryPDA })
+        .rpc();
+      coin = await createSovereignCoin("CHFR", "CHF", chfFiatMint, chfBondMint);
+    });
+
+    function bondPDA(mint: PublicKey) {
+      return PublicKey.findProgramAddressSync([Buffer.from("bond"), mint.toBuffer()], STABLEBOND_PROGRAM_ID)[0];
+    }
+
+    async function chfMapping() {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      return factory.bondMappings.find((m) => Buffer.from(m.fiatCurrency).equals(Buffer.from(fiatCurrency)));
+    }
+
+    async function rolloverBonds(newMint: PublicKey) {
+      // The local bond mints are plain SPL mints with no Stablebond accounts behind them
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      const newBondHolding = Keypair.generate();
+      return program.methods
+        .rolloverBonds()
+        .accounts({
+          cranker: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          bondAccount: sovereignCoin.bondAccount,
+          issuance: Keypair.generate().publicKey,
+          bondMint: chfBondMint,
+          bondHolding: coin.bondHolding,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: chfFiatMint,
+          sellLiquidity: Keypair.generate().publicKey,
+          sellLiquidityTokenAccount: Keypair.generate().publicKey,
+          feeCollectorTokenAccount: Keypair.generate().publicKey,
+          newBondAccount: bondPDA(newMint),
+          newIssuance: Keypair.generate().publicKey,
+          newBondMint: newMint,
+          newBondHolding: newBondHolding.publicKey,
+          payment: Keypair.generate().publicKey,
+          paymentTokenAccount: Keypair.generate().publicKey,
+          paymentFeed: Keypair.generate().publicKey,
+          paymentBasePriceFeed: Keypair.generate().publicKey,
+          paymentQuotePriceFeed: null,
+          stablebondProgram: STABLEBOND_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newBondHolding])
+        .rpc();
+    }
+
+    it("Counts coins against the mapping's bond mint", async () => {
+      const mapping = await chfMapping();
+      expect(mapping.coinCount.toNumber()).to.equal(1);
+      expect(mapping.previousCoinCount.toNumber()).to.equal(0);
+    });
+
+    it("Should fail to roll over before the mapping moves to a new issue", async () => {
+      try {
+        await rolloverBonds(newBondMint);
+        expect.fail("Transaction should have failed with invalid bond mint");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidBondMint");
+      }
+    });
+
+    it("Parks the coins on the old mint when the mapping moves through the timelock", async () => {
+      await applyFactoryChange({
+        bondMapping: { fiatCurrency, bondMint: newBondMint, bondRating: 1, active: true },
+      });
+
+      const mapping = await chfMapping();
+      expect(mapping.bondMint.toString()).to.equal(newBondMint.toString());
+      expect(mapping.coinCount.toNumber()).to.equal(0);
+      expect(mapping.previousBondMint.toString()).to.equal(chfBondMint.toString());
+      expect(mapping.previousCoinCount.toNumber()).to.equal(1);
+    });
+
+    it("Should fail to move the mapping again before its coins roll over", async () => {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      const [pendingChange] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_change"), factoryPDA.toBuffer(), factory.nextChangeId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      try {
+        await program.methods
+          .queueFactoryChange({ bondMapping: { fiatCurrency, bondMint: chfBondMint, bondRating: 1, active: true } })
+          .accounts({ authority: authority, factory: factoryPDA, pendingChange })
+          .rpc();
+        expect.fail("Transaction should have failed with bond mint in use");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("BondMintInUse");
+      }
+    });
+
+    it("Should fail to roll over into a mint the mapping does not point at", async () => {
+      const otherBondMint = await createMint(
+        provider.connection, mintAuthority, authority, null, 6, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      );
+      try {
+        await rolloverBonds(otherBondMint);
+        expect.fail("Transaction should have failed with invalid bond mint");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidBondMint");
+      }
+    });
+
+    it("Should fail to roll over a bond the Stablebond program does not own", async () => {
+      try {
+        await rolloverBonds(newBondMint);
+        expect.fail("Transaction should have failed with invalid Stablebond account");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidStablebondAccount");
+      }
+
+      // Nothing moved, so the coin still counts against the old mint
+      const mapping = await chfMapping();
+      expect(mapping.previousCoinCount.toNumber()).to.equal(1);
+    });
+  });
+
+  describe("reserve rebalancing", () => {
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let keeperFiatAccount: PublicKey;
+
+    before(async () => {
+      coin = await createSovereignCoin("USDK", "USD", usdFiatMint, usdBondMint);
+      keeperFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+
+      await program.methods
+        .updateManualPrice(new anchor.BN(1_000_000), new anchor.BN(1_000))
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          manualPrice: manualPricePDA(usdBondMint),
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await setRebalancing(0, 0);
+    });
+
+    async function setRebalancing(rebalanceBandBps: number, keeperRewardBps: number) {
+      await applyFactoryChange({ rebalancing: { rebalanceBandBps, keeperRewardBps } });
+    }
+
+    async function rebalanceReserves() {
+      // Stablebond accounts are only read once a trade is needed
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      return program.methods
+        .rebalanceReserves()
+        .accounts({
+          keeper: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          priceOracle: manualPricePDA(usdBondMint),
+          bondAccount: sovereignCoin.bondAccount,
+          issuance: Keypair.generate().publicKey,
+          bondMint: usdBondMint,
+          bondHolding: coin.bondHolding,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          keeperFiatAccount,
+          payment: Keypair.generate().publicKey,
+          paymentTokenAccount: Keypair.generate().publicKey,
+          sellLiquidity: Keypair.generate().publicKey,
+          sellLiquidityTokenAccount: Keypair.generate().publicKey,
+          feeCollectorTokenAccount: Keypair.generate().publicKey,
+          paymentFeed: Keypair.generate().publicKey,
+          paymentBasePriceFeed: Keypair.generate().publicKey,
+          paymentQuotePriceFeed: null,
+          stablebondProgram: new PublicKey("BondyhA24H696Y1HudTyBGzZH58PMPCeAoSinHdWMa1f"),
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    it("Should fail to rebalance while no band is configured", async () => {
+      try {
+        await rebalanceReserves();
+        expect.fail("Transaction should have failed with rebalancing disabled");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("RebalancingDisabled");
+      }
+    });
+
+    it("Should fail to queue a keeper reward above the maximum", async () => {
+      try {
+        await setRebalancing(500, 101);
+        expect.fail("Transaction should have failed with keeper reward too high");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("KeeperRewardTooHigh");
+      }
+    });
+
+    it("Should fail to rebalance a reserve inside the band", async () => {
+      await setRebalancing(500, 10);
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.rebalanceBandBps).to.equal(500);
+      expect(factory.keeperRewardBps).to.equal(10);
+
+      // No supply and no fiat sits exactly on target
+      try {
+        await rebalanceReserves();
+        expect.fail("Transaction should have failed with reserves within band");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("ReservesWithinBand");
+      }
+    });
+  });
+
+  describe("insurance fund", () => {
+    const [insuranceAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance"), factoryPDA.toBuffer()],
+      program.programId
+    );
+    const [insuranceVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance_vault"), Buffer.from("USD")],
+      program.programId
+    );
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let holderCoinAccount: PublicKey;
+    let holderFiatAccount: PublicKey;
+    let redemptionEscrow: PublicKey;
+    let previousFees: { mintFeeBps: number; burnFeeBps: number };
+
+    before(async () => {
+      coin = await createSovereignCoin("USDN", "USD", usdFiatMint, usdBondMint);
+      holderCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      holderFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      [redemptionEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_escrow"), coin.sovereignCoin.toBuffer()],
+        program.programId
+      );
+      await issueCoins(coin, usdFiatMint, holderCoinAccount, 10_000_000);
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      previousFees = { mintFeeBps: factory.mintFeeBps, burnFeeBps: factory.burnFeeBps };
+
+      // 1% redemption fee, half of it to insurance
+      await applyFactoryChange({ fees: { mintFeeBps: 0, burnFeeBps: 100 } });
+      await applyFactoryChange({ insuranceShare: { insuranceFeeShareBps: 5_000 } });
+    });
+
+    after(async () => {
+      await applyFactoryChange({ insuranceShare: { insuranceFeeShareBps: 0 } });
+      await applyFactoryChange({ fees: previousFees });
+    });
+
+    function settleRedemption(vault: PublicKey | null) {
+      const [ticket] = PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_ticket"), coin.sovereignCoin.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      return program.methods
+        .settleRedemption()
+        .accounts({
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          ticket,
+          owner: authority,
+          redemptionEscrow,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          fiatDestination: holderFiatAccount,
+          treasuryFiatAccount: holderFiatAccount,
+          issuerFiatAccount: holderFiatAccount,
+          insuranceVault: vault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    function coverShortfall(
+      target: { sovereignCoin: PublicKey; mint: PublicKey; fiatReserve: PublicKey },
+      vault: PublicKey,
+      fiatMint: PublicKey,
+      amount: number
+    ) {
+      return program.methods
+        .coverShortfall(new anchor.BN(amount))
+        .accounts({
+          treasurer: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          sovereignCoin: target.sovereignCoin,
+          mint: target.mint,
+          insuranceAuthority,
+          insuranceVault: vault,
+          fiatReserve: target.fiatReserve,
+          fiatTokenMint: fiatMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    function initInsuranceVault(currency: string, vault: PublicKey, fiatMint: PublicKey) {
+      return program.methods
+        .initInsuranceVault(currency)
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          insuranceAuthority,
+          insuranceVault: vault,
+          fiatTokenMint: fiatMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    it("Creates the USD insurance vault", async () => {
+      await initInsuranceVault("USD", insuranceVault, usdFiatMint);
+
+      const vault = await getAccount(provider.connection, insuranceVault);
+      expect(vault.owner.toString()).to.equal(insuranceAuthority.toString());
+      expect(vault.mint.toString()).to.equal(usdFiatMint.toString());
+    });
+
+    it("Routes the insurance share of redemption fees to the vault", async () => {
+      const [ticket] = PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_ticket"), coin.sovereignCoin.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .requestRedemption(new anchor.BN(10_000_000))
+        .accounts({
+          holder: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          holderCoinAccount,
+          redemptionEscrow,
+          fiatReserve: coin.fiatReserve,
+          fiatDestination: holderFiatAccount,
+          ticket,
+          marketMaker: null,
+          allowlistEntry: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await settleRedemption(null);
+        expect.fail("Transaction should have failed with insurance vault required");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InsuranceVaultRequired");
+      }
+
+      await settleRedemption(insuranceVault);
+      expect(await balance(insuranceVault)).to.equal(50_000);
+      expect(await balance(coin.fiatReserve)).to.equal(0);
+    });
+
+    it("Reports the fund balance in the reserve status", async () => {
+      const status = await program.methods
+        .getReserveStatus()
+        .accounts({
+          sovereignCoin: coin.sovereignCoin,
+          factory: factoryPDA,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          bondHolding: coin.bondHolding,
+          priceOracle: manualPricePDA(usdBondMint),
+          bondTokenMint: usdBondMint,
+          fiatTokenMint: usdFiatMint,
+          insuranceVault,
+        })
+        .view();
+
+      expect(status.insuranceFundBalance.toNumber()).to.equal(50_000);
+    });
+
+    it("Routes the insurance share of mint fees to the vault", async () => {
+      // 1% mint fee, so 10_000 of the 1_000_000 paid for is protocol fee and half
+      // of that is paid to the vault in fiat instead of being minted
+      await applyFactoryChange({ fees: { mintFeeBps: 100, burnFeeBps: 100 } });
+      await mintTo(provider.connection, mintAuthority, usdFiatMint, holderFiatAccount, mintAuthority, 1_000_000);
+      const mint = (vault: PublicKey | null) => program.methods
+        .mintSovereignCoin(new anchor.BN(1_000_000))
+        .accounts({
+          user: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          userFiatAccount: holderFiatAccount,
+          userCoinAccount: holderCoinAccount,
+          treasuryCoinAccount: holderCoinAccount,
+          issuerCoinAccount: holderCoinAccount,
+          insuranceVault: vault,
+          marketMaker: null,
+          allowlistEntry: null,
+          referrer: null,
+          referralEscrow: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      try {
+        try {
+          await mint(null);
+          expect.fail("Transaction should have failed with insurance vault required");
+        } catch (err) {
+          expect(err.error.errorCode.code).to.equal("InsuranceVaultRequired");
+        }
+
+        await mint(insuranceVault);
+        expect(await balance(insuranceVault)).to.equal(55_000);
+        expect(await balance(coin.fiatReserve)).to.equal(995_000);
+        expect(await balance(holderCoinAccount)).to.equal(995_000);
+        const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+        expect(sovereignCoin.fiatAmount.toNumber()).to.equal(995_000);
+      } finally {
+        await applyFactoryChange({ fees: { mintFeeBps: 0, burnFeeBps: 100 } });
+      }
+    });
+
+    it("Should fail to cover a coin that meets its fiat reserve", async () => {
+      try {
+        await coverShortfall(coin, insuranceVault, usdFiatMint, 20_000);
+        expect.fail("Transaction should have failed with no shortfall");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NoShortfall");
+      }
+    });
+
+    describe("with a shortfall", () => {
+      const [eurVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("insurance_vault"), Buffer.from("EUR")],
+        program.programId
+      );
+      let eurCoin: Awaited<ReturnType<typeof createSovereignCoin>>;
+      let deficit: number;
+
+      before(async () => {
+        // Coins made under a reserve minimum of 100% need more fiat than
+        // minting at par deposits, so this one starts short
+        await applyFactoryChange({ reserveParameters: { minFiatReservePercentage: 100, bondReserveMultiplier: 90 } });
+        try {
+          eurCoin = await createSovereignCoin("EURN", "EUR", eurFiatMint, await getRegisteredBondMint("EUR"));
+        } finally {
+          await applyFactoryChange({
+            reserveParameters: { minFiatReservePercentage: minFiatReserve, bondReserveMultiplier: bondReserveMultiplier },
+          });
+        }
+        const holderEurCoin = (await getOrCreateAssociatedTokenAccount(
+          provider.connection, mintAuthority, eurCoin.mint, authority
+        )).address;
+        await issueCoins(eurCoin, eurFiatMint, holderEurCoin, 1_000_000);
+        await initInsuranceVault("EUR", eurVault, eurFiatMint);
+
+        const sovereignCoin = await program.account.sovereignCoin.fetch(eurCoin.sovereignCoin);
+        expect(sovereignCoin.requiredReservePercentage).to.be.greaterThan(100);
+        deficit = 1_000_000 * (sovereignCoin.requiredReservePercentage - 100) / 100;
+      });
+
+      it("Should fail to cover more than the fund holds", async () => {
+        await mintTo(provider.connection, mintAuthority, eurFiatMint, eurVault, mintAuthority, deficit - 1);
+
+        try {
+          await coverShortfall(eurCoin, eurVault, eurFiatMint, 2 * deficit);
+          expect.fail("Transaction should have failed with insufficient insurance fund");
+        } catch (err) {
+          expect(err.error.errorCode.code).to.equal("InsufficientInsuranceFund");
+        }
+      });
+
+      it("Lets the treasurer cover the shortfall, capped at the deficit", async () => {
+        await mintTo(provider.connection, mintAuthority, eurFiatMint, eurVault, mintAuthority, 10_001);
+        await coverShortfall(eurCoin, eurVault, eurFiatMint, 2 * deficit);
+
+        expect(await balance(eurVault)).to.equal(10_000);
+        expect(await balance(eurCoin.fiatReserve)).to.equal(1_000_000 + deficit);
+        const sovereignCoin = await program.account.sovereignCoin.fetch(eurCoin.sovereignCoin);
+        expect(sovereignCoin.fiatAmount.toNumber()).to.equal(1_000_000 + deficit);
+
+        try {
+          await coverShortfall(eurCoin, eurVault, eurFiatMint, 1);
+          expect.fail("Transaction should have failed with no shortfall");
+        } catch (err) {
+          expect(err.error.errorCode.code).to.equal("NoShortfall");
+        }
+      });
+    });
+  });
+
+  describe("referrals", () => {
+    const referrerWallet = Keypair.generate();
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let userCoinAccount: PublicKey;
+    let userFiatAccount: PublicKey;
+    let referrerCoinAccount: PublicKey;
+    let referralEscrow: PublicKey;
+    let previousFees: { mintFeeBps: number; burnFeeBps: number };
+
+    function referrerPDA(wallet: PublicKey) {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("referrer"), coin.sovereignCoin.toBuffer(), wallet.toBuffer()],
+        program.programId
+      )[0];
+    }
+
+    before(async () => {
+      coin = await createSovereignCoin("USDE", "USD", usdFiatMint, usdBondMint);
+
+      const sig = await provider.connection.requestAirdrop(referrerWallet.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+
+      // The user is also the treasury and the issuer here
+      userCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      userFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      referrerCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, referrerWallet.publicKey
+      )).address;
+      [referralEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("referral_escrow"), coin.sovereignCoin.toBuffer()],
+        program.programId
+      );
+      await mintTo(provider.connection, mintAuthority, usdFiatMint, userFiatAccount, mintAuthority, 20_000_000);
+
+      const factory = await program.account.factory.fetch(factoryPDA);
+      previousFees = { mintFeeBps: factory.mintFeeBps, burnFeeBps: factory.burnFeeBps };
+
+      // 1% mint fee, half of it to the referrer
+      await applyFactoryChange({ fees: { mintFeeBps: 100, burnFeeBps: previousFees.burnFeeBps } });
+      await applyFactoryChange({ referralShare: { referralShareBps: 5_000 } });
+    });
+
+    after(async () => {
+      await applyFactoryChange({ referralShare: { referralShareBps: 0 } });
+      await applyFactoryChange({ fees: previousFees });
+    });
+
+    function registerReferrer(wallet: PublicKey, signers: Keypair[]) {
+      return program.methods
+        .registerReferrer()
+        .accounts({
+          wallet,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          referrer: referrerPDA(wallet),
+          referralEscrow,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers(signers)
+        .rpc();
+    }
+
+    function mint(amount: number, referrer: PublicKey | null) {
+      return program.methods
+        .mintSovereignCoin(new anchor.BN(amount))
+        .accounts({
+          user: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          userFiatAccount,
+          userCoinAccount,
+          treasuryCoinAccount: userCoinAccount,
+          issuerCoinAccount: userCoinAccount,
+          insuranceVault: null,
+          marketMaker: null,
+          allowlistEntry: null,
+          referrer: referrer && referrerPDA(referrer),
+          referralEscrow: referrer && referralEscrow,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    it("Mints against deposited fiat without a referrer", async () => {
+      await mint(10_000_000, null);
+
+      // The whole protocol fee goes to the treasury, which is the user here
+      expect(await balance(userCoinAccount)).to.equal(10_000_000);
+      expect(await balance(coin.fiatReserve)).to.equal(10_000_000);
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.fiatAmount.toNumber()).to.equal(10_000_000);
+    });
+
+    it("Registers a referrer", async () => {
+      await registerReferrer(referrerWallet.publicKey, [referrerWallet]);
+
+      const referrer = await program.account.referrer.fetch(referrerPDA(referrerWallet.publicKey));
+      expect(referrer.wallet.toString()).to.equal(referrerWallet.publicKey.toString());
+      expect(referrer.sovereignCoin.toString()).to.equal(coin.sovereignCoin.toString());
+      expect(referrer.referralCount.toNumber()).to.equal(0);
+    });
+
+    it("Accrues the referral share of the mint fee", async () => {
+      await mint(10_000_000, referrerWallet.publicKey);
+
+      expect(await balance(referralEscrow)).to.equal(50_000);
+      expect(await balance(userCoinAccount)).to.equal(19_950_000);
+      const referrer = await program.account.referrer.fetch(referrerPDA(referrerWallet.publicKey));
+      expect(referrer.referralCount.toNumber()).to.equal(1);
+      expect(referrer.totalVolume.toNumber()).to.equal(10_000_000);
+      expect(referrer.accrued.toNumber()).to.equal(50_000);
+    });
+
+    it("Should fail to refer your own mint", async () => {
+      await registerReferrer(authority, []);
+
+      try {
+        await mint(1_000_000, authority);
+        expect.fail("Transaction should have failed with self referral");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("SelfReferral");
+      }
+    });
+
+    it("Lets the referrer claim accrued fees", async () => {
+      const claim = () => program.methods
+        .claimReferralFees()
+        .accounts({
+          wallet: referrerWallet.publicKey,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          referrer: referrerPDA(referrerWallet.publicKey),
+          referralEscrow,
+          destination: referrerCoinAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([referrerWallet])
+        .rpc();
+
+      await claim();
+      expect(await balance(referrerCoinAccount)).to.equal(50_000);
+      expect(await balance(referralEscrow)).to.equal(0);
+      const referrer = await program.account.referrer.fetch(referrerPDA(referrerWallet.publicKey));
+      expect(referrer.accrued.toNumber()).to.equal(0);
+      expect(referrer.totalClaimed.toNumber()).to.equal(50_000);
+
+      try {
+        await claim();
+        expect.fail("Transaction should have failed with nothing to claim");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NothingToClaim");
+      }
+    });
+  });
+
+  describe("issuer onboarding", () => {
+    const issuer = Keypair.generate();
+    const creationFee = 10_000_000;
+
+    before(async () => {
+      const sig = await provider.connection.requestAirdrop(issuer.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+
+      await program.methods
+        .registerIssuer("MX")
+        .accounts({
+          issuer: issuer.publicKey,
+          issuerProfile: issuerProfilePDA(issuer.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([issuer])
+        .rpc();
+    });
+
+    after(async () => {
+      await applyFactoryChange({ creationFee: { coinCreationFee: new anchor.BN(0) } });
+    });
+
+    function approveIssuer(approver: Keypair | null, coinQuota: number) {
+      return program.methods
+        .approveIssuer(coinQuota)
+        .accounts({
+          authority: approver ? approver.publicKey : authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          issuerProfile: issuerProfilePDA(issuer.publicKey),
+        })
+        .signers(approver ? [approver] : [])
+        .rpc();
+    }
+
+    function initCoin(symbol: string) {
+      const [sovereignCoin] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sovereign_coin"), issuer.publicKey.toBuffer(), Buffer.from(symbol)],
+        program.programId
+      );
+      return program.methods
+        .initSovereignCoin({ name: `${symbol} Sovereign`, symbol, uri: "", fiatCurrency: "USD" })
+        .accounts({
+          payer: issuer.publicKey,
+          authority: issuer.publicKey,
+          factory: factoryPDA,
+          issuerProfile: issuerProfilePDA(issuer.publicKey),
+          treasury: authority,
+          sovereignCoin,
+          fiatTokenMint: usdFiatMint,
+          bondTokenMint: usdBondMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([issuer])
+        .rpc();
+    }
+
+    it("Should fail to approve an issuer without the issuer admin role", async () => {
+      try {
+        await approveIssuer(issuer, 1);
+        expect.fail("Transaction should have failed with unauthorized");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Charges the creation fee to the treasury", async () => {
+      await approveIssuer(null, 1);
+      await applyFactoryChange({ creationFee: { coinCreationFee: new anchor.BN(creationFee) } });
+
+      const before = await provider.connection.getBalance(authority);
+      const sig = await initCoin("MXO1");
+      const tx = await provider.connection.getTransaction(sig, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+      const after = await provider.connection.getBalance(authority);
+
+      // The treasury also paid the transaction fee
+      expect(after - before).to.equal(creationFee - tx.meta.fee);
+      const profile = await program.account.issuerProfile.fetch(issuerProfilePDA(issuer.publicKey));
+      expect(profile.coinsCreated).to.equal(1);
+    });
+
+    it("Should fail to create coins beyond the quota", async () => {
+      try {
+        await initCoin("MXO2");
+        expect.fail("Transaction should have failed with quota exceeded");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("IssuerQuotaExceeded");
+      }
+    });
+
+    it("Should fail to create coins once revoked", async () => {
+      await approveIssuer(null, 2);
+      await program.methods
+        .revokeIssuer()
+        .accounts({
+          authority: authority,
+          factory: factoryPDA,
+          factoryRoles: rolesPDA(factoryPDA),
+          issuerProfile: issuerProfilePDA(issuer.publicKey),
+        })
+        .rpc();
+
+      try {
+        await initCoin("MXO2");
+        expect.fail("Transaction should have failed with issuer revoked");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("IssuerRevoked");
+      }
+    });
+  });
+
+  describe("primary market allowlist", () => {
+    let coin: Awaited<ReturnType<typeof createSovereignCoin>>;
+    let userCoinAccount: PublicKey;
+    let userFiatAccount: PublicKey;
+    let allowlistEntry: PublicKey;
+    let redemptionEscrow: PublicKey;
+
+    before(async () => {
+      coin = await createSovereignCoin("USDA", "USD", usdFiatMint, usdBondMint);
+
+      userCoinAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, coin.mint, authority
+      )).address;
+      userFiatAccount = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, mintAuthority, usdFiatMint, authority
+      )).address;
+      [allowlistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("primary_market_allowlist"), coin.sovereignCoin.toBuffer(), authority.toBuffer()],
+        program.programId
+      );
+      [redemptionEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_escrow"), coin.sovereignCoin.toBuffer()],
+        program.programId
+      );
+      await mintTo(provider.connection, mintAuthority, usdFiatMint, userFiatAccount, mintAuthority, 1_000_000);
+    });
+
+    function setPermissionless(permissionless: boolean) {
+      return program.methods
+        .setPermissionless(permissionless)
+        .accounts({
+          authority: authority,
+          sovereignCoin: coin.sovereignCoin,
+          coinRoles: rolesPDA(coin.sovereignCoin),
+        })
+        .rpc();
+    }
+
+    function mint(entry: PublicKey | null) {
+      // The user is also the treasury and the issuer here
+      return program.methods
+        .mintSovereignCoin(new anchor.BN(1_000_000))
+        .accounts({
+          user: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          fiatReserve: coin.fiatReserve,
+          fiatTokenMint: usdFiatMint,
+          userFiatAccount,
+          userCoinAccount,
+          treasuryCoinAccount: userCoinAccount,
+          issuerCoinAccount: userCoinAccount,
+          insuranceVault: null,
+          marketMaker: null,
+          allowlistEntry: entry,
+          referrer: null,
+          referralEscrow: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          fiatTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    function requestRedemption(entry: PublicKey | null) {
+      const [ticket] = PublicKey.findProgramAddressSync(
+        [Buffer.from("redemption_ticket"), coin.sovereignCoin.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      return program.methods
+        .requestRedemption(new anchor.BN(500_000))
+        .accounts({
+          holder: authority,
+          factory: factoryPDA,
+          sovereignCoin: coin.sovereignCoin,
+          mint: coin.mint,
+          holderCoinAccount: userCoinAccount,
+          redemptionEscrow,
+          fiatReserve: coin.fiatReserve,
+          fiatDestination: userFiatAccount,
+          ticket,
+          marketMaker: null,
+          allowlistEntry: entry,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    it("Should fail to mint without an allowlist entry once restricted", async () => {
+      await setPermissionless(false);
+
+      try {
+        await mint(null);
+        expect.fail("Transaction should have failed with not allowlisted");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NotAllowlisted");
+      }
+    });
+
+    it("Lets an allowlisted wallet mint", async () => {
+      await program.methods
+        .addToAllowlist(authority)
+        .accounts({
+          payer: authority,
+          authority: authority,
+          sovereignCoin: coin.sovereignCoin,
+          coinRoles: rolesPDA(coin.sovereignCoin),
+          allowlistEntry,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await mint(allowlistEntry);
+      expect(await balance(userCoinAccount)).to.equal(1_000_000);
+    });
+
+    it("Should fail to redeem after removal from the allowlist", async () => {
+      await program.methods
+        .removeFromAllowlist(authority)
+        .accounts({
+          rentReceiver: authority,
+          authority: authority,
+          sovereignCoin: coin.sovereignCoin,
+          coinRoles: rolesPDA(coin.sovereignCoin),
+          allowlistEntry,
+        })
+        .rpc();
+
+      try {
+        await requestRedemption(null);
+        expect.fail("Transaction should have failed with not allowlisted");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NotAllowlisted");
+      }
+    });
+
+    it("Lets anyone redeem once permissionless again", async () => {
+      await setPermissionless(true);
+      await requestRedemption(null);
+
+      const sovereignCoin = await program.account.sovereignCoin.fetch(coin.sovereignCoin);
+      expect(sovereignCoin.queuedRedemptions.toNumber()).to.equal(500_000);
+    });
+  });
+
+  describe("account migration", () => {
+    // Loaded by the validator from tests/fixtures, see Anchor.toml
+    const legacyCoin = new PublicKey("C41kGKXAH7F9sMNJY3rNZtUCuV27sCoD96Q7NJnFAXAK");
+    const legacyIssuer = Keypair.fromSecretKey(Uint8Array.from(JSON.parse(
+      fs.readFileSync(path.join(__dirname, "fixtures", "legacy_issuer.json"), "utf8")
+    )));
+
+    before(async () => {
+      const sig = await provider.connection.requestAirdrop(legacyIssuer.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+    });
+
+    function migrateSovereignCoin(signer: Keypair | null) {
+      return program.methods
+        .migrateSovereignCoin()
+        .accounts({
+          authority: signer ? signer.publicKey : authority,
+          sovereignCoin: legacyCoin,
+          coinRoles: rolesPDA(legacyCoin),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers(signer ? [signer] : [])
+        .rpc();
+    }
+
+    it("Should fail to deserialize the v1 coin fixture before migration", async () => {
+      const info = await provider.connection.getAccountInfo(legacyCoin);
+      expect(info.owner.toString()).to.equal(program.programId.toString());
+
+      let decoded = true;
+      try {
+        await program.account.sovereignCoin.fetch(legacyCoin);
+      } catch (err) {
+        decoded = false;
+      }
+      expect(decoded).to.be.false;
+    });
+
+    it("Should fail to migrate a coin without the issuer admin role", async () => {
+      try {
+        await migrateSovereignCoin(null);
+        expect.fail("Transaction should have failed with unauthorized");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("Upgrades the v1 coin fixture in place", async () => {
+      const before = await provider.connection.getAccountInfo(legacyCoin);
+      await migrateSovereignCoin(legacyIssuer);
+
+      const after = await provider.connection.getAccountInfo(legacyCoin);
+      expect(after.data.length).to.equal(before.data.length + 1 + 128); // version and reserved
+      expect(after.data.subarray(0, before.data.length).equals(before.data)).to.be.true;
+
+      const sovereignCoin = await program.account.sovereignCoin.fetch(legacyCoin);
+      expect(sovereignCoin.version).to.equal(2);
+      expect(sovereignCoin.reserved.every((byte: number) => byte === 0)).to.be.true;
+      expect(sovereignCoin.authority.toString()).to.equal(legacyIssuer.publicKey.toString());
+      expect(Buffer.from(sovereignCoin.symbol).toString().replace(/\0/g, "")).to.equal("USDV");
+      expect(sovereignCoin.maxSupply.toString()).to.equal("1000000000000");
+      expect(sovereignCoin.permissionless).to.be.true;
+    });
+
+    it("Should fail to migrate an account already in the current layout", async () => {
+      try {
+        await migrateSovereignCoin(legacyIssuer);
+        expect.fail("Transaction should have failed with already migrated");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("AccountAlreadyMigrated");
+      }
+
+      try {
+        await program.methods
+          .migrateFactory()
+          .accounts({
+            authority: authority,
+            factory: factoryPDA,
+            factoryRoles: rolesPDA(factoryPDA),
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Transaction should have failed with already migrated");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("AccountAlreadyMigrated");
+      }
+    });
+
+    it("Creates new accounts at the current version", async () => {
+      const factory = await program.account.factory.fetch(factoryPDA);
+      expect(factory.version).to.equal(3);
+    });
+  });
+});
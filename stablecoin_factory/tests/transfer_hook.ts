@@ -0,0 +1,430 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { StablecoinFactory } from "../target/types/stablecoin_factory";
+import { ComplianceHook } from "../target/types/compliance_hook";
+import { PublicKey, Keypair, Transaction } from "@solana/web3.js";
+import {
+  createMint,
+  createAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+// Runs after stablecoin_factory.ts, which initializes the factory and registers USD
+describe("compliance_hook", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.StablecoinFactory as Program<StablecoinFactory>;
+  const hookProgram = anchor.workspace.ComplianceHook as Program<ComplianceHook>;
+  const authority = provider.wallet.publicKey;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+
+  const [factoryPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("factory")],
+    program.programId
+  );
+
+  const symbol = "USDH";
+  const [sovereignCoinPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("sovereign_coin"), authority.toBuffer(), Buffer.from(symbol)],
+    program.programId
+  );
+  const mint = Keypair.generate();
+  const fiatReserve = Keypair.generate();
+  const bondHolding = Keypair.generate();
+  const alice = Keypair.generate();
+  const bob = Keypair.generate();
+
+  let aliceToken: PublicKey;
+  let bobToken: PublicKey;
+
+  const [accessListPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("access_list"), mint.publicKey.toBuffer()],
+    hookProgram.programId
+  );
+  const [extraAccountMetaListPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), mint.publicKey.toBuffer()],
+    hookProgram.programId
+  );
+  const [coinRolesPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("roles"), sovereignCoinPDA.toBuffer()],
+    program.programId
+  );
+  const accessEntryPDA = (wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("access_entry"), mint.publicKey.toBuffer(), wallet.toBuffer()],
+      hookProgram.programId
+    )[0];
+
+  async function transfer(from: PublicKey, to: PublicKey, owner: Keypair, amount: number) {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      from,
+      mint.publicKey,
+      to,
+      owner.publicKey,
+      BigInt(amount),
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    await provider.sendAndConfirm(new Transaction().add(ix), [owner]);
+  }
+
+  // Hook failures surface through the token program, so match on the logs
+  function expectHookError(err: any, code: string) {
+    const logs: string[] = err.logs ?? [];
+    expect(logs.join("\n") + err.toString()).to.include(code);
+  }
+
+  // The coin PDA signs the seizure, so the hook accounts are resolved for it
+  async function seize(from: PublicKey, to: PublicKey, amount: number) {
+    const transferIx = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      from,
+      mint.publicKey,
+      to,
+      sovereignCoinPDA,
+      BigInt(amount),
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    await program.methods
+      .seizeHolder(new anchor.BN(amount), "court order")
+      .accounts({
+        complianceOfficer: authority,
+        sovereignCoin: sovereignCoinPDA,
+        coinRoles: coinRolesPDA,
+        mint: mint.publicKey,
+        source: from,
+        destination: to,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(transferIx.keys.slice(4).map((key) => ({ ...key, isSigner: false })))
+      .rpc();
+  }
+
+  async function addEntry(wallet: PublicKey) {
+    await hookProgram.methods
+      .addAccessEntry(wallet)
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        accessList: accessListPDA,
+        accessEntry: accessEntryPDA(wallet),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  async function removeEntry(wallet: PublicKey) {
+    await hookProgram.methods
+      .removeAccessEntry(wallet)
+      .accounts({
+        rentReceiver: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        accessList: accessListPDA,
+        accessEntry: accessEntryPDA(wallet),
+      })
+      .rpc();
+  }
+
+  it("Creates a Token-2022 coin with the compliance hook installed", async () => {
+    const factory = await program.account.factory.fetch(factoryPDA);
+    const usdMapping = factory.bondMappings.find(
+      (m) => m.active && Buffer.from(m.fiatCurrency).toString().replace(/\0/g, "") === "USD"
+    );
+    const fiatMint = await createMint(provider.connection, payer, authority, null, 6);
+
+    await program.methods
+      .initSovereignCoin({
+        name: "US Dollar Hooked",
+        symbol,
+        uri: "https://example.com/usdh.json",
+        fiatCurrency: "USD",
+      })
+      .accounts({
+        payer: authority,
+        authority: authority,
+        factory: factoryPDA,
+        sovereignCoin: sovereignCoinPDA,
+        fiatTokenMint: fiatMint,
+        bondTokenMint: usdMapping.bondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    await program.methods
+      .setupToken2022Mint({ transferHook: true, interestBearing: false, permanentDelegate: true })
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        mint: mint.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    const sovereignCoinAccount = await program.account.sovereignCoin.fetch(sovereignCoinPDA);
+    expect(sovereignCoinAccount.transferHookEnabled).to.equal(true);
+
+    await program.methods
+      .setupTokenAccounts()
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        fiatReserve: fiatReserve.publicKey,
+        bondHolding: bondHolding.publicKey,
+        fiatTokenMint: fiatMint,
+        bondTokenMint: usdMapping.bondMint,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([fiatReserve, bondHolding])
+      .rpc();
+
+    await hookProgram.methods
+      .initializeAccessList({ allowlist: {} })
+      .accounts({
+        payer: authority,
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        mint: mint.publicKey,
+        accessList: accessListPDA,
+        extraAccountMetaList: extraAccountMetaListPDA,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const accessList = await hookProgram.account.accessList.fetch(accessListPDA);
+    expect(accessList.sovereignCoin.toString()).to.equal(sovereignCoinPDA.toString());
+    expect(accessList.mode).to.deep.equal({ allowlist: {} });
+    expect(accessList.entriesCount).to.equal(0);
+
+    // Minting does not run the hook, so Alice can be issued coins before she is listed.
+    // The test wallet is the treasury and the issuer, so it takes the fees.
+    aliceToken = await createAccount(provider.connection, payer, mint.publicKey, alice.publicKey, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+    bobToken = await createAccount(provider.connection, payer, mint.publicKey, bob.publicKey, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+    const walletToken = (await getOrCreateAssociatedTokenAccount(
+      provider.connection, payer, mint.publicKey, authority, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+    )).address;
+    const walletFiat = (await getOrCreateAssociatedTokenAccount(provider.connection, payer, fiatMint, authority)).address;
+    await mintTo(provider.connection, payer, fiatMint, walletFiat, payer, 1_000_000);
+    await program.methods
+      .mintSovereignCoin(new anchor.BN(1_000_000))
+      .accounts({
+        user: authority,
+        factory: factoryPDA,
+        sovereignCoin: sovereignCoinPDA,
+        mint: mint.publicKey,
+        fiatReserve: fiatReserve.publicKey,
+        fiatTokenMint: fiatMint,
+        userFiatAccount: walletFiat,
+        userCoinAccount: aliceToken,
+        treasuryCoinAccount: walletToken,
+        issuerCoinAccount: walletToken,
+        insuranceVault: null,
+        marketMaker: null,
+        allowlistEntry: null,
+        referrer: null,
+        referralEscrow: null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        fiatTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("Should fail to transfer between wallets that are not allowlisted", async () => {
+    try {
+      await transfer(aliceToken, bobToken, alice, 100_000);
+      expect.fail("Transaction should have failed with wallets not allowlisted");
+    } catch (err) {
+      expectHookError(err, "NotAllowlisted");
+    }
+  });
+
+  it("Allows transfers once both wallets are allowlisted", async () => {
+    await addEntry(alice.publicKey);
+
+    try {
+      await transfer(aliceToken, bobToken, alice, 100_000);
+      expect.fail("Transaction should have failed with destination not allowlisted");
+    } catch (err) {
+      expectHookError(err, "NotAllowlisted");
+    }
+
+    await addEntry(bob.publicKey);
+    await transfer(aliceToken, bobToken, alice, 100_000);
+
+    const bobAccount = await getAccount(provider.connection, bobToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(Number(bobAccount.amount)).to.equal(100_000);
+
+    const accessList = await hookProgram.account.accessList.fetch(accessListPDA);
+    expect(accessList.entriesCount).to.equal(2);
+  });
+
+  it("Blocks listed wallets in denylist mode", async () => {
+    await hookProgram.methods
+      .setAccessListMode({ denylist: {} })
+      .accounts({
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        accessList: accessListPDA,
+      })
+      .rpc();
+
+    // Both wallets are still listed, so now both are denied
+    try {
+      await transfer(aliceToken, bobToken, alice, 100_000);
+      expect.fail("Transaction should have failed with denylisted wallets");
+    } catch (err) {
+      expectHookError(err, "Denylisted");
+    }
+
+    await removeEntry(alice.publicKey);
+    await removeEntry(bob.publicKey);
+    await transfer(aliceToken, bobToken, alice, 100_000);
+
+    const bobAccount = await getAccount(provider.connection, bobToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(Number(bobAccount.amount)).to.equal(200_000);
+
+    const accessList = await hookProgram.account.accessList.fetch(accessListPDA);
+    expect(accessList.entriesCount).to.equal(0);
+  });
+
+  it("Lets the compliance officer seize from a denylisted holder", async () => {
+    await addEntry(alice.publicKey);
+    await seize(aliceToken, bobToken, 100_000);
+
+    const aliceAccount = await getAccount(provider.connection, aliceToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const bobAccount = await getAccount(provider.connection, bobToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(Number(aliceAccount.amount)).to.equal(700_000);
+    expect(Number(bobAccount.amount)).to.equal(300_000);
+  });
+
+  it("Lets the compliance officer seize into an unlisted account in allowlist mode", async () => {
+    await hookProgram.methods
+      .setAccessListMode({ allowlist: {} })
+      .accounts({
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        accessList: accessListPDA,
+      })
+      .rpc();
+
+    // Bob is not listed, so he could not receive a regular transfer
+    await seize(aliceToken, bobToken, 100_000);
+
+    const bobAccount = await getAccount(provider.connection, bobToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(Number(bobAccount.amount)).to.equal(400_000);
+
+    await removeEntry(alice.publicKey);
+  });
+
+  it("Should fail to manage the list without the coin authority", async () => {
+    const outsider = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+
+    try {
+      await hookProgram.methods
+        .addAccessEntry(outsider.publicKey)
+        .accounts({
+          payer: outsider.publicKey,
+          authority: outsider.publicKey,
+          sovereignCoin: sovereignCoinPDA,
+          accessList: accessListPDA,
+          accessEntry: accessEntryPDA(outsider.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc();
+
+      expect.fail("Transaction should have failed with unauthorized authority");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("Charges the flash mint fee through the hook", async () => {
+    const walletToken = getAssociatedTokenAddressSync(mint.publicKey, authority, false, TOKEN_2022_PROGRAM_ID);
+    const walletBefore = await getAccount(provider.connection, walletToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    await addEntry(alice.publicKey);
+    await addEntry(authority);
+
+    await program.methods
+      .setFlashMintConfig(new anchor.BN(10_000_000), 30)
+      .accounts({
+        authority: authority,
+        sovereignCoin: sovereignCoinPDA,
+        coinRoles: coinRolesPDA,
+      })
+      .rpc();
+
+    const flashMintIx = await program.methods
+      .flashMint(new anchor.BN(10_000_000))
+      .accounts({
+        borrower: alice.publicKey,
+        factory: factoryPDA,
+        sovereignCoin: sovereignCoinPDA,
+        mint: mint.publicKey,
+        receiver: aliceToken,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+    // The fee leg runs the hook, so its accounts are resolved for Alice paying the treasury
+    const feeIx = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      aliceToken,
+      mint.publicKey,
+      walletToken,
+      alice.publicKey,
+      BigInt(30_000),
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    const flashRepayIx = await program.methods
+      .flashRepay()
+      .accounts({
+        payer: alice.publicKey,
+        factory: factoryPDA,
+        sovereignCoin: sovereignCoinPDA,
+        mint: mint.publicKey,
+        payerCoinAccount: aliceToken,
+        treasuryCoinAccount: walletToken,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(feeIx.keys.slice(4).map((key) => ({ ...key, isSigner: false })))
+      .instruction();
+    await provider.sendAndConfirm(new Transaction().add(flashMintIx, flashRepayIx), [alice]);
+
+    // 0.3% of 10 coins
+    const aliceAccount = await getAccount(provider.connection, aliceToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const walletAfter = await getAccount(provider.connection, walletToken, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(Number(aliceAccount.amount)).to.equal(600_000 - 30_000);
+    expect(Number(walletAfter.amount - walletBefore.amount)).to.equal(30_000);
+
+    await removeEntry(alice.publicKey);
+    await removeEntry(authority);
+  });
+});